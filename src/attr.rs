@@ -151,9 +151,9 @@ fn format_derive(
         argument_shape.width,
     );
     let trailing_separator = match context.config.indent_style() {
-        // We always add the trailing comma and remove it if it is not needed.
-        IndentStyle::Block => SeparatorTactic::Always,
-        IndentStyle::Visual => SeparatorTactic::Never,
+        // Add the trailing comma unless disabled, and remove it if it is not needed.
+        IndentStyle::Block if context.config.derive_trailing_comma() => SeparatorTactic::Always,
+        IndentStyle::Block | IndentStyle::Visual => SeparatorTactic::Never,
     };
 
     // Format the collection of items.
@@ -310,8 +310,12 @@ impl Rewrite for ast::MetaItem {
             }
             ast::MetaItemKind::NameValue(ref literal) => {
                 let path = rewrite_path(context, PathContext::Type, None, &self.path, shape)?;
-                // 3 = ` = `
-                let lit_shape = shape.shrink_left(path.len() + 3)?;
+                let eq = if context.config.space_around_attr_eq() {
+                    " = "
+                } else {
+                    "="
+                };
+                let lit_shape = shape.shrink_left(path.len() + eq.len())?;
                 // `rewrite_literal` returns `None` when `literal` exceeds max
                 // width. Since a literal is basically unformattable unless it
                 // is a string literal (and only if `format_strings` is set),
@@ -320,7 +324,7 @@ impl Rewrite for ast::MetaItem {
                 // See #2479 for example.
                 let value = rewrite_literal(context, literal, lit_shape)
                     .unwrap_or_else(|| context.snippet(literal.span).to_owned());
-                format!("{} = {}", path, value)
+                format!("{}{}{}", path, eq, value)
             }
         })
     }
@@ -5,18 +5,21 @@ use thiserror::Error;
 
 use rustfmt_nightly as rustfmt;
 
+use std::cmp;
 use std::collections::HashMap;
 use std::env;
 use std::fs::File;
 use std::io::{self, stdout, Read, Write};
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
 
 use getopts::{Matches, Options};
 
 use crate::rustfmt::{
-    load_config, CliOptions, Color, Config, Edition, EmitMode, FileLines, FileName,
-    FormatReportFormatterBuilder, Input, Session, Verbosity,
+    is_valid_backup_suffix, load_config, CliOptions, Color, Config, Edition, EmitMode, FileLines,
+    FileName, FormatReportFormatterBuilder, Input, Session, Verbosity,
 };
 
 fn main() {
@@ -55,6 +58,10 @@ enum Operation {
     ConfigOutputDefault { path: Option<String> },
     /// Output current config (as if formatting to a file) to stdout
     ConfigOutputCurrent { path: Option<String> },
+    /// Output the options of the current config that differ from the defaults to stdout
+    ConfigOutputDiff { path: Option<String> },
+    /// Check a config file, or the config file in a directory (the current one if None)
+    CheckConfig { path: Option<String> },
     /// No file specified, read from stdin
     Stdin { input: String },
 }
@@ -106,19 +113,30 @@ fn make_opts() -> Options {
         "Run in 'check' mode. Exits with 0 if input is formatted correctly. Exits \
          with 1 and prints a diff if formatting is required.",
     );
+    opts.optflag(
+        "",
+        "verbose-diff",
+        "Print the diffs of `--check` as unified diffs, with `---` and `+++` file headers.",
+    );
     let is_nightly = is_nightly();
     let emit_opts = if is_nightly {
-        "[files|stdout|coverage|checkstyle|json]"
+        "[files|stdout|files-with-diff|coverage|checkstyle|json]"
     } else {
-        "[files|stdout]"
+        "[files|stdout|files-with-diff]"
     };
     opts.optopt("", "emit", "What data to emit and how", emit_opts);
     opts.optflag("", "backup", "Backup any modified files.");
+    opts.optopt(
+        "",
+        "backup-suffix",
+        "Extension given to the backups made by `--backup` (default `bk`)",
+        "EXT",
+    );
     opts.optopt(
         "",
         "config-path",
-        "Recursively searches the given path for the rustfmt.toml config file. If not \
-         found reverts to the input file path",
+        "Uses the given rustfmt.toml config file, or searches the given directory and its \
+         parents for one. If not provided, the search starts from each input file's directory",
         "[Path for the configuration file]",
     );
     opts.optopt("", "edition", "Rust edition to use", "[2015|2018]");
@@ -133,8 +151,17 @@ fn make_opts() -> Options {
         "print-config",
         "Dumps a default or minimal config to PATH. A minimal config is the \
          subset of the current config file used for formatting the current program. \
-         `current` writes to stdout current config as if formatting the file at PATH.",
-        "[default|minimal|current] PATH",
+         `current` writes to stdout current config as if formatting the file at PATH. \
+         `diff` writes to stdout only the options of the current config that differ \
+         from the defaults.",
+        "[default|minimal|current|diff] PATH",
+    );
+    opts.optflag(
+        "",
+        "check-config",
+        "Checks the config file at PATH, or the config file in the directory PATH \
+         (by default the current directory), for unknown options, invalid values and \
+         conflicting options without formatting any code. Exits with 1 if there are problems.",
     );
     opts.optflag(
         "l",
@@ -142,12 +169,32 @@ fn make_opts() -> Options {
         "Prints the names of mismatched files that were formatted. Prints the names of \
          files that would be formated when used with `--check` mode. ",
     );
+    opts.optflag(
+        "",
+        "null",
+        "Terminate the file names printed by `--emit files-with-diff` with NUL instead of \
+         a newline, for use with `xargs -0`, and read NUL-separated paths from `--files-from`.",
+    );
+    opts.optopt(
+        "",
+        "files-from",
+        "Format the files listed in FILE, one path per line (or NUL-separated with `--null`). \
+         `-` reads the list from stdin.",
+        "FILE",
+    );
     opts.optmulti(
         "",
         "config",
         "Set options from command line. These settings take priority over .rustfmt.toml",
         "[key1=val1,key2=val2...]",
     );
+    opts.optopt(
+        "j",
+        "jobs",
+        "Number of files to format concurrently (default 1). Ignored with \
+         `--emit checkstyle` or `--emit json`.",
+        "N",
+    );
 
     if is_nightly {
         opts.optflag(
@@ -242,6 +289,31 @@ fn execute(opts: &Options) -> Result<i32> {
 
             Ok(0)
         }
+        Operation::ConfigOutputDiff { path } => {
+            let path = match path {
+                Some(path) => path,
+                None => return Err(format_err!("PATH required for `--print-config diff`")),
+            };
+
+            let file = PathBuf::from(path);
+            let file = file.canonicalize().unwrap_or(file);
+
+            let (config, _) = load_config(Some(file.parent().unwrap()), Some(options))?;
+            let mut stdout = io::stdout();
+            for (name, value) in config.diff_from_default() {
+                writeln!(stdout, "{} = {}", name, value)?;
+            }
+
+            Ok(0)
+        }
+        Operation::CheckConfig { path } => {
+            let path = path.map_or_else(|| PathBuf::from("."), PathBuf::from);
+            let problems = Config::check_toml_path(&path)?;
+            for problem in &problems {
+                eprintln!("Error: {}", problem);
+            }
+            Ok(if problems.is_empty() { 0 } else { 1 })
+        }
         Operation::Stdin { input } => format_string(input, options),
         Operation::Format {
             files,
@@ -301,6 +373,16 @@ fn format(
         }
     }
 
+    // Checkstyle and JSON wrap the output of all files in a single document, and a minimal
+    // config is collected from the options used across all files, so these need one session.
+    let jobs = options.jobs.unwrap_or(1);
+    if jobs > 1
+        && minimal_config_path.is_none()
+        && !matches!(config.emit_mode(), EmitMode::Checkstyle | EmitMode::Json)
+    {
+        return format_in_parallel(files, config, config_path.is_none(), options, jobs);
+    }
+
     let out = &mut stdout();
     let mut session = Session::new(config, Some(out));
 
@@ -317,12 +399,13 @@ fn format(
                 let (local_config, config_path) =
                     load_config(Some(file.parent().unwrap()), Some(options.clone()))?;
                 if local_config.verbose() == Verbosity::Verbose {
-                    if let Some(path) = config_path {
-                        println!(
+                    match config_path {
+                        Some(path) => println!(
                             "Using rustfmt config file {} for {}",
                             path.display(),
                             file.display()
-                        );
+                        ),
+                        None => println!("Using default configuration for {}", file.display()),
                     }
                 }
 
@@ -345,7 +428,7 @@ fn format(
 
     let exit_code = if session.has_operational_errors()
         || session.has_parsing_errors()
-        || ((session.has_diff() || session.has_check_errors()) && options.check)
+        || ((session.has_diff() || session.has_check_errors()) && options.fails_on_diff())
     {
         1
     } else {
@@ -354,6 +437,150 @@ fn format(
     Ok(exit_code)
 }
 
+/// What formatting a single file produced. Files may be formatted concurrently, so this is
+/// held back until all the files before it have been reported.
+#[derive(Default)]
+struct FileOutcome {
+    stdout: Vec<u8>,
+    stderr: String,
+    has_errors: bool,
+    has_diff: bool,
+}
+
+fn format_in_parallel(
+    files: Vec<PathBuf>,
+    config: Config,
+    use_local_config: bool,
+    options: &GetOptsOptions,
+    jobs: usize,
+) -> Result<i32> {
+    let num_workers = cmp::min(jobs, files.len());
+    let queue = Arc::new(Mutex::new(files.into_iter().enumerate()));
+    let (sender, receiver) = mpsc::channel();
+    let workers = (0..num_workers)
+        .map(|_| {
+            let queue = Arc::clone(&queue);
+            let sender = sender.clone();
+            let config = config.clone();
+            let options = options.clone();
+            thread::spawn(move || {
+                loop {
+                    let next = queue.lock().unwrap().next();
+                    let (index, file) = match next {
+                        Some(next) => next,
+                        None => break,
+                    };
+                    let outcome = format_file(file, &config, use_local_config, &options);
+                    if sender.send((index, outcome)).is_err() {
+                        break;
+                    }
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+    drop(sender);
+
+    // Report the files in the order they were given, holding back those that finish early.
+    let mut pending = HashMap::new();
+    let mut next_index = 0;
+    let mut has_errors = false;
+    let mut has_diff = false;
+    for (index, outcome) in receiver {
+        pending.insert(index, outcome);
+        while let Some(outcome) = pending.remove(&next_index) {
+            let outcome = outcome?;
+            stdout().write_all(&outcome.stdout)?;
+            eprint!("{}", outcome.stderr);
+            has_errors |= outcome.has_errors;
+            has_diff |= outcome.has_diff;
+            next_index += 1;
+        }
+    }
+    for worker in workers {
+        if worker.join().is_err() {
+            return Err(format_err!("a thread formatting files panicked"));
+        }
+    }
+
+    let exit_code = if has_errors || (has_diff && options.fails_on_diff()) {
+        1
+    } else {
+        0
+    };
+    Ok(exit_code)
+}
+
+/// Formats a single file with its own session, capturing what would be printed.
+fn format_file(
+    file: PathBuf,
+    config: &Config,
+    use_local_config: bool,
+    options: &GetOptsOptions,
+) -> Result<FileOutcome> {
+    let mut outcome = FileOutcome::default();
+    if !file.exists() {
+        outcome.stderr = format!("Error: file `{}` does not exist\n", file.to_str().unwrap());
+        outcome.has_errors = true;
+        return Ok(outcome);
+    } else if file.is_dir() {
+        outcome.stderr = format!("Error: `{}` is a directory\n", file.to_str().unwrap());
+        outcome.has_errors = true;
+        return Ok(outcome);
+    }
+
+    let config = if use_local_config {
+        let (local_config, config_path) =
+            load_config(Some(file.parent().unwrap()), Some(options.clone()))?;
+        if local_config.verbose() == Verbosity::Verbose {
+            match config_path {
+                Some(path) => writeln!(
+                    outcome.stdout,
+                    "Using rustfmt config file {} for {}",
+                    path.display(),
+                    file.display()
+                )?,
+                None => writeln!(
+                    outcome.stdout,
+                    "Using default configuration for {}",
+                    file.display()
+                )?,
+            }
+        }
+        local_config
+    } else {
+        config.clone()
+    };
+
+    let mut out = Vec::new();
+    {
+        let mut session = Session::new(config, Some(&mut out));
+        session.capture_diagnostics();
+        let result = session.format(Input::File(file));
+        let diagnostics = session.take_diagnostics();
+        outcome.stderr = String::from_utf8_lossy(&diagnostics).into_owned();
+        match result {
+            Ok(report) => {
+                if report.has_warnings() {
+                    outcome.stderr += &format!(
+                        "{}\n",
+                        FormatReportFormatterBuilder::new(&report)
+                            .enable_colors(should_print_with_colors(&mut session))
+                            .build()
+                    );
+                }
+            }
+            Err(msg) => {
+                outcome.stderr += &format!("Error writing files: {}\n", msg);
+                session.add_operational_error();
+            }
+        }
+        outcome.has_errors = session.has_operational_errors() || session.has_parsing_errors();
+        outcome.has_diff = session.has_diff() || session.has_check_errors();
+    }
+    outcome.stdout.extend(out);
+    Ok(outcome)
+}
+
 fn format_and_emit_report<T: Write>(session: &mut Session<'_, T>, input: Input) {
     match session.format(input) {
         Ok(report) => {
@@ -448,12 +675,18 @@ fn determine_operation(matches: &Matches) -> Result<Operation, OperationError> {
     }
     let mut free_matches = matches.free.iter();
 
+    if matches.opt_present("check-config") {
+        let path = free_matches.next().cloned();
+        return Ok(Operation::CheckConfig { path });
+    }
+
     let mut minimal_config_path = None;
     if let Some(kind) = matches.opt_str("print-config") {
         let path = free_matches.next().cloned();
         match kind.as_str() {
             "default" => return Ok(Operation::ConfigOutputDefault { path }),
             "current" => return Ok(Operation::ConfigOutputCurrent { path }),
+            "diff" => return Ok(Operation::ConfigOutputDiff { path }),
             "minimal" => {
                 minimal_config_path = path;
                 if minimal_config_path.is_none() {
@@ -470,7 +703,7 @@ fn determine_operation(matches: &Matches) -> Result<Operation, OperationError> {
         return Ok(Operation::Version);
     }
 
-    let files: Vec<_> = free_matches
+    let mut files: Vec<_> = free_matches
         .map(|s| {
             let p = PathBuf::from(s);
             // we will do comparison later, so here tries to canonicalize first
@@ -479,8 +712,13 @@ fn determine_operation(matches: &Matches) -> Result<Operation, OperationError> {
         })
         .collect();
 
+    let files_from = matches.opt_str("files-from");
+    if let Some(ref manifest) = files_from {
+        files.extend(read_files_from(manifest, matches.opt_present("null"))?);
+    }
+
     // if no file argument is supplied, read from stdin
-    if files.is_empty() {
+    if files.is_empty() && files_from.is_none() {
         if minimal_config_path.is_some() {
             return Err(OperationError::MinimalPathWithStdin);
         }
@@ -496,7 +734,46 @@ fn determine_operation(matches: &Matches) -> Result<Operation, OperationError> {
     })
 }
 
-const STABLE_EMIT_MODES: [EmitMode; 3] = [EmitMode::Files, EmitMode::Stdout, EmitMode::Diff];
+/// Reads the paths listed in the manifest given to `--files-from`, which is read from stdin if
+/// it is `-`. Paths which don't exist are skipped with a warning.
+fn read_files_from(manifest: &str, null_separated: bool) -> Result<Vec<PathBuf>, OperationError> {
+    let mut buffer = String::new();
+    if manifest == "-" {
+        io::stdin().read_to_string(&mut buffer)?;
+    } else {
+        File::open(manifest)?.read_to_string(&mut buffer)?;
+    }
+
+    let paths: Vec<&str> = if null_separated {
+        buffer.split('\0').collect()
+    } else {
+        buffer.lines().collect()
+    };
+    let files = paths
+        .into_iter()
+        .filter(|path| !path.is_empty())
+        .map(PathBuf::from)
+        .filter(|path| {
+            let exists = path.exists();
+            if !exists {
+                eprintln!(
+                    "Warning: skipping `{}` listed in `--files-from`, it does not exist",
+                    path.display()
+                );
+            }
+            exists
+        })
+        .map(|path| path.canonicalize().unwrap_or(path))
+        .collect();
+    Ok(files)
+}
+
+const STABLE_EMIT_MODES: [EmitMode; 4] = [
+    EmitMode::Files,
+    EmitMode::Stdout,
+    EmitMode::Diff,
+    EmitMode::FilesWithDiff,
+];
 
 /// Parsed command line options.
 #[derive(Clone, Debug, Default)]
@@ -509,12 +786,16 @@ struct GetOptsOptions {
     emit_mode: Option<EmitMode>,
     backup: bool,
     check: bool,
+    verbose_diff: bool,
     edition: Option<Edition>,
     color: Option<Color>,
     file_lines: FileLines, // Default is all lines in all files.
     unstable_features: bool,
     error_on_unformatted: Option<bool>,
     print_misformatted_file_names: bool,
+    null_separated_file_names: bool,
+    jobs: Option<usize>,
+    backup_suffix: Option<String>,
 }
 
 impl GetOptsOptions {
@@ -597,6 +878,10 @@ impl GetOptsOptions {
             options.emit_mode = Some(emit_mode_from_emit_str(emit_str)?);
         }
 
+        if matches.opt_present("verbose-diff") {
+            options.verbose_diff = true;
+        }
+
         if let Some(ref edition_str) = matches.opt_str("edition") {
             options.edition = Some(edition_from_edition_str(edition_str)?);
         }
@@ -605,10 +890,31 @@ impl GetOptsOptions {
             options.backup = true;
         }
 
+        if let Some(backup_suffix) = matches.opt_str("backup-suffix") {
+            if !is_valid_backup_suffix(&backup_suffix) {
+                return Err(format_err!(
+                    "Invalid value for `--backup-suffix`: {}",
+                    backup_suffix
+                ));
+            }
+            options.backup_suffix = Some(backup_suffix);
+        }
+
         if matches.opt_present("files-with-diff") {
             options.print_misformatted_file_names = true;
         }
 
+        if matches.opt_present("null") {
+            if options.emit_mode != Some(EmitMode::FilesWithDiff)
+                && !matches.opt_present("files-from")
+            {
+                return Err(format_err!(
+                    "`--null` can only be used with `--emit files-with-diff` or `--files-from`"
+                ));
+            }
+            options.null_separated_file_names = true;
+        }
+
         if !rust_nightly {
             if let Some(ref emit_mode) = options.emit_mode {
                 if !STABLE_EMIT_MODES.contains(emit_mode) {
@@ -620,6 +926,13 @@ impl GetOptsOptions {
             }
         }
 
+        if let Some(ref jobs) = matches.opt_str("jobs") {
+            match jobs.parse() {
+                Ok(jobs) if jobs > 0 => options.jobs = Some(jobs),
+                _ => return Err(format_err!("Invalid value for `--jobs`: {}", jobs)),
+            }
+        }
+
         if let Some(ref color) = matches.opt_str("color") {
             match Color::from_str(color) {
                 Ok(color) => options.color = Some(color),
@@ -630,6 +943,11 @@ impl GetOptsOptions {
         Ok(options)
     }
 
+    // Whether files which would be reformatted make rustfmt exit with 1.
+    fn fails_on_diff(&self) -> bool {
+        self.check || self.emit_mode == Some(EmitMode::FilesWithDiff)
+    }
+
     fn verify_file_lines(&self, files: &[PathBuf]) {
         for f in self.file_lines.files() {
             match *f {
@@ -668,15 +986,24 @@ impl CliOptions for GetOptsOptions {
         } else if let Some(emit_mode) = self.emit_mode {
             config.set().emit_mode(emit_mode);
         }
+        if self.verbose_diff {
+            config.set().verbose_diff(true);
+        }
         if self.backup {
             config.set().make_backup(true);
         }
+        if let Some(backup_suffix) = self.backup_suffix {
+            config.set().backup_suffix(backup_suffix);
+        }
         if let Some(color) = self.color {
             config.set().color(color);
         }
         if self.print_misformatted_file_names {
             config.set().print_misformatted_file_names(true);
         }
+        if self.null_separated_file_names {
+            config.set().null_separated_file_names(true);
+        }
 
         for (key, val) in self.inline_config {
             config.override_value(&key, &val);
@@ -704,6 +1031,7 @@ fn emit_mode_from_emit_str(emit_str: &str) -> Result<EmitMode> {
         "coverage" => Ok(EmitMode::Coverage),
         "checkstyle" => Ok(EmitMode::Checkstyle),
         "json" => Ok(EmitMode::Json),
+        "files-with-diff" => Ok(EmitMode::FilesWithDiff),
         _ => Err(format_err!("Invalid value for `--emit`")),
     }
 }
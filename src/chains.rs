@@ -708,7 +708,10 @@ impl<'a> ChainFormatter for ChainFormatterBlock<'a> {
         let mut root_rewrite: String = parent.rewrite(context, shape)?;
 
         let mut root_ends_with_block = parent.kind.is_block_like(context, &root_rewrite);
-        let tab_width = context.config.tab_spaces().saturating_sub(shape.offset);
+        let tab_width = context
+            .config
+            .continuation_indent_width_or_default()
+            .saturating_sub(shape.offset);
 
         while root_rewrite.len() <= tab_width && !root_rewrite.contains('\n') {
             let item = &self.shared.children[0];
@@ -738,7 +741,7 @@ impl<'a> ChainFormatter for ChainFormatterBlock<'a> {
             if self.root_ends_with_block {
                 shape.block_indent(0)
             } else {
-                shape.block_indent(context.config.tab_spaces())
+                shape.block_indent(context.config.continuation_indent_width_or_default())
             }
             .with_max_width(context.config),
         )
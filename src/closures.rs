@@ -11,7 +11,9 @@ use crate::overflow::OverflowableItem;
 use crate::rewrite::{Rewrite, RewriteContext};
 use crate::shape::Shape;
 use crate::source_map::SpanUtils;
-use crate::utils::{last_line_width, left_most_sub_expr, stmt_expr, NodeIdExt};
+use crate::utils::{
+    last_line_width, left_most_sub_expr, stmt_expr, strip_tail_return, NodeIdExt,
+};
 
 // This module is pretty messy because of the rules around closures and blocks:
 // FIXME - the below is probably no longer true in full.
@@ -41,6 +43,20 @@ pub(crate) fn rewrite_closure(
     // 1 = space between `|...|` and body.
     let body_shape = shape.offset_left(extra_offset)?;
 
+    let stripped_body = match body.kind {
+        ast::ExprKind::Block(ref block, label) => {
+            strip_tail_return(context, block).map(|block| ast::Expr {
+                id: body.id,
+                kind: ast::ExprKind::Block(ptr::P(block), label),
+                span: body.span,
+                attrs: body.attrs.clone(),
+                tokens: None,
+            })
+        }
+        _ => None,
+    };
+    let body = stripped_body.as_ref().unwrap_or(body);
+
     if let ast::ExprKind::Block(ref block, _) = body.kind {
         // The body of the closure is an empty block.
         if block.stmts.is_empty() && !block_contains_comment(context, block) {
@@ -141,7 +157,9 @@ fn rewrite_closure_with_block(
     shape: Shape,
 ) -> Option<String> {
     let left_most = left_most_sub_expr(body);
-    let veto_block = veto_block(body) && !expr_requires_semi_to_be_stmt(left_most);
+    let veto_block = !context.config.closure_body_braces()
+        && veto_block(body)
+        && !expr_requires_semi_to_be_stmt(left_most);
     if veto_block {
         return None;
     }
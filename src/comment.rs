@@ -350,8 +350,17 @@ fn identify_comment(
     };
 
     let (first_group, rest) = orig.split_at(first_group_ending);
+    let preserve_layout = !config.normalize_comments() || config.preserve_block_comment_indent();
     let rewritten_first_group =
-        if !config.normalize_comments() && has_bare_lines && style.is_block_comment() {
+        if config.preserve_inner_doc_comments() && style == CommentStyle::Doc {
+            // Treat inner doc comments as pre-formatted prose: only their indentation changes.
+            // Trailing whitespace is kept as well, whatever `trim_comment_trailing_whitespace`.
+            first_group
+                .lines()
+                .map(str::trim_start)
+                .collect::<Vec<_>>()
+                .join(&format!("\n{}", shape.indent.to_string(config)))
+        } else if preserve_layout && has_bare_lines && style.is_block_comment() {
             trim_left_preserve_layout(first_group, shape.indent, config)?
         } else if !config.normalize_comments()
             && !config.wrap_comments()
@@ -407,16 +416,21 @@ enum CodeBlockAttribute {
 }
 
 impl CodeBlockAttribute {
-    fn new(attribute: &str) -> CodeBlockAttribute {
-        match attribute {
-            "rust" | "" => CodeBlockAttribute::Rust,
-            "ignore" => CodeBlockAttribute::Ignore,
-            "text" => CodeBlockAttribute::Text,
-            "should_panic" => CodeBlockAttribute::ShouldPanic,
-            "no_run" => CodeBlockAttribute::NoRun,
-            "compile_fail" => CodeBlockAttribute::CompileFail,
-            _ => CodeBlockAttribute::Text,
+    /// Parses a comma separated list of attributes (e.g., `rust,no_run`). Any attribute other
+    /// than `rust` or `should_panic` marks the block as one that must not be formatted.
+    fn new(attributes: &str) -> CodeBlockAttribute {
+        let mut code_block_attr = CodeBlockAttribute::Rust;
+        for attribute in attributes.split(',') {
+            match attribute.trim() {
+                "rust" | "" => (),
+                "should_panic" => code_block_attr = CodeBlockAttribute::ShouldPanic,
+                "ignore" => return CodeBlockAttribute::Ignore,
+                "no_run" => return CodeBlockAttribute::NoRun,
+                "compile_fail" => return CodeBlockAttribute::CompileFail,
+                _ => return CodeBlockAttribute::Text,
+            }
         }
+        code_block_attr
     }
 }
 
@@ -615,6 +629,13 @@ impl<'a> CommentRewrite<'a> {
         self.result
     }
 
+    /// Wraps `line` to the current shape, unless doing so would break a URL, a path or a code
+    /// span across lines, however narrow.
+    fn wrap_line(&self, line: &str) -> Option<String> {
+        rewrite_string(line, &self.fmt, self.max_width)
+            .filter(|s| !splits_token(line, s, &self.line_start))
+    }
+
     fn handle_line(
         &mut self,
         orig: &'a str,
@@ -650,13 +671,24 @@ impl<'a> CommentRewrite<'a> {
         } else if self.code_block_attr.is_some() {
             if line.starts_with("```") {
                 let code_block = match self.code_block_attr.as_ref().unwrap() {
-                    CodeBlockAttribute::Ignore | CodeBlockAttribute::Text => {
+                    CodeBlockAttribute::Ignore
+                    | CodeBlockAttribute::Text
+                    | CodeBlockAttribute::NoRun
+                    | CodeBlockAttribute::CompileFail => {
                         trim_custom_comment_prefix(&self.code_block_buffer)
                     }
                     _ if self.code_block_buffer.is_empty() => String::new(),
                     _ => {
                         let mut config = self.fmt.config.clone();
                         config.set().wrap_comments(false);
+                        // Leave room for the indentation and the comment prefix (e.g., `/// `)
+                        // which are added back to every line of the formatted code.
+                        let code_block_width = config
+                            .doc_comment_code_block_width()
+                            .saturating_sub(self.fmt_indent.width() + self.line_start.len());
+                        if code_block_width < config.max_width() {
+                            config.set().max_width(code_block_width);
+                        }
                         if config.format_code_in_doc_comments() {
                             if let Some(s) =
                                 crate::format_code_block(&self.code_block_buffer, &config, false)
@@ -697,6 +729,8 @@ impl<'a> CommentRewrite<'a> {
             return false;
         }
 
+        let is_markdown_header = self.fmt.config.wrap_comments() && is_markdown_header(line);
+
         if self.result == self.opener {
             let force_leading_whitespace = &self.opener == "/* " && count_newlines(orig) == 0;
             if !has_leading_whitespace && !force_leading_whitespace && self.result.ends_with(' ') {
@@ -705,7 +739,7 @@ impl<'a> CommentRewrite<'a> {
             if line.is_empty() {
                 return false;
             }
-        } else if self.is_prev_line_multi_line && !line.is_empty() {
+        } else if self.is_prev_line_multi_line && !line.is_empty() && !is_markdown_header {
             self.result.push(' ')
         } else if is_last && line.is_empty() {
             // trailing blank lines are unwanted
@@ -723,8 +757,9 @@ impl<'a> CommentRewrite<'a> {
         if self.fmt.config.wrap_comments()
             && unicode_str_width(line) > self.fmt.shape.width
             && !has_url(line)
+            && !is_markdown_header
         {
-            match rewrite_string(line, &self.fmt, self.max_width) {
+            match self.wrap_line(line) {
                 Some(ref s) => {
                     self.is_prev_line_multi_line = s.contains('\n');
                     self.result.push_str(s);
@@ -735,7 +770,7 @@ impl<'a> CommentRewrite<'a> {
                     self.result.pop();
                     self.result.push_str(&self.comment_line_separator);
                     self.fmt.shape = Shape::legacy(self.max_width, self.fmt_indent);
-                    match rewrite_string(line, &self.fmt, self.max_width) {
+                    match self.wrap_line(line) {
                         Some(ref s) => {
                             self.is_prev_line_multi_line = s.contains('\n');
                             self.result.push_str(s);
@@ -792,7 +827,7 @@ fn rewrite_comment_inner(
         .lines()
         .enumerate()
         .map(|(i, mut line)| {
-            line = trim_end_unless_two_whitespaces(line.trim_start(), is_doc_comment);
+            line = trim_end_unless_two_whitespaces(line.trim_start(), is_doc_comment, config);
             // Drop old closer.
             if i == line_breaks && line.ends_with("*/") && !line.starts_with("//") {
                 line = line[..(line.len() - 2)].trim_end();
@@ -852,6 +887,54 @@ fn has_url(s: &str) -> bool {
     s.contains("https://") || s.contains("http://") || s.contains("ftp://") || s.contains("file://")
 }
 
+/// Returns `true` if `wrapped`, the result of wrapping `line` onto lines starting with
+/// `line_start`, breaks one of the tokens of `line` across two lines.
+fn splits_token(line: &str, wrapped: &str, line_start: &str) -> bool {
+    let line_start = line_start.trim();
+    let mut wrapped_tokens = vec![];
+    for (i, wrapped_line) in wrapped.lines().enumerate() {
+        let text = wrapped_line.trim_start();
+        let text = if i == 0 {
+            text
+        } else {
+            text.strip_prefix(line_start).unwrap_or(text)
+        };
+        wrapped_tokens.extend(comment_tokens(text));
+    }
+    comment_tokens(line) != wrapped_tokens
+}
+
+/// Splits the given line into the tokens that wrapping must keep whole: runs of non-whitespace
+/// characters such as URLs or paths, and Markdown code spans (e.g., `` `a b` ``).
+fn comment_tokens(line: &str) -> Vec<&str> {
+    let mut tokens = vec![];
+    let mut in_code_span = false;
+    let mut token_start = None;
+    for (i, c) in line.char_indices() {
+        if c == '`' {
+            in_code_span = !in_code_span;
+        }
+        if c.is_whitespace() && !in_code_span {
+            if let Some(start) = token_start.take() {
+                tokens.push(&line[start..i]);
+            }
+        } else if token_start.is_none() {
+            token_start = Some(i);
+        }
+    }
+    if let Some(start) = token_start {
+        tokens.push(&line[start..]);
+    }
+    tokens
+}
+
+/// Returns `true` if the given line is an ATX-style Markdown header (e.g., `# Examples`).
+/// Headers are kept on their own line and are never reflowed with the surrounding text.
+fn is_markdown_header(line: &str) -> bool {
+    let level = line.chars().take_while(|&c| c == '#').count();
+    (1..=6).contains(&level) && line[level..].starts_with(' ')
+}
+
 /// Given the span, rewrite the missing comment inside it if available.
 /// Note that the given span must only include comments (or leading/trailing whitespaces).
 pub(crate) fn rewrite_missing_comment(
@@ -898,9 +981,14 @@ pub(crate) fn recover_missing_comment_in_span(
     }
 }
 
-/// Trim trailing whitespaces unless they consist of two or more whitespaces.
-fn trim_end_unless_two_whitespaces(s: &str, is_doc_comment: bool) -> &str {
-    if is_doc_comment && s.ends_with("  ") {
+/// Trim trailing whitespaces unless they consist of two or more whitespaces, or trimming them
+/// is disabled by `trim_comment_trailing_whitespace`.
+fn trim_end_unless_two_whitespaces<'a>(
+    s: &'a str,
+    is_doc_comment: bool,
+    config: &Config,
+) -> &'a str {
+    if !config.trim_comment_trailing_whitespace() || (is_doc_comment && s.ends_with("  ")) {
         s
     } else {
         s.trim_end()
@@ -931,7 +1019,7 @@ fn light_rewrite_comment(
                 ""
             };
             // Preserve markdown's double-space line break syntax in doc comment.
-            trim_end_unless_two_whitespaces(left_trimmed, is_doc_comment)
+            trim_end_unless_two_whitespaces(left_trimmed, is_doc_comment, config)
         })
         .collect();
     lines.join(&format!("\n{}", offset.to_string(config)))
@@ -1901,6 +1989,36 @@ mod test {
         check("\"/* abc", "abc", Some(4));
     }
 
+    #[test]
+    fn test_comment_tokens() {
+        assert_eq!(comment_tokens(" lorem  ipsum "), vec!["lorem", "ipsum"]);
+        assert_eq!(
+            comment_tokens("see crate::lorem::ipsum, `dolor sit` amet"),
+            vec!["see", "crate::lorem::ipsum,", "`dolor sit`", "amet"]
+        );
+    }
+
+    #[test]
+    fn test_splits_token() {
+        let line = "use `lorem ipsum` and crate::dolor::sit here";
+        assert!(!splits_token(line, line, "/// "));
+        assert!(!splits_token(
+            line,
+            "use `lorem ipsum` and\n    /// crate::dolor::sit here",
+            "/// "
+        ));
+        assert!(splits_token(
+            line,
+            "use `lorem\n    /// ipsum` and crate::dolor::sit here",
+            "/// "
+        ));
+        assert!(splits_token(
+            line,
+            "use `lorem ipsum` and crate::\n * dolor::sit here",
+            " * "
+        ));
+    }
+
     #[test]
     fn test_filter_normal_code() {
         let s = r#"
@@ -6,6 +6,15 @@ pub(crate) trait ConfigType: Sized {
     /// Returns hint text for use in `Config::print_docs()`. For enum types, this is a
     /// pipe-separated list of variants; for other types it returns "<type>".
     fn doc_hint() -> String;
+
+    /// Returns `true` if `self` and `other` are the same option value, ignoring any state which
+    /// isn't set by the user.
+    fn is_same_value(&self, other: &Self) -> bool
+    where
+        Self: PartialEq,
+    {
+        self == other
+    }
 }
 
 impl ConfigType for bool {
@@ -48,6 +57,11 @@ impl ConfigType for IgnoreList {
     fn doc_hint() -> String {
         String::from("[<string>,..]")
     }
+
+    // The directory of the config file is not part of the option's value.
+    fn is_same_value(&self, other: &Self) -> bool {
+        self.path_set() == other.path_set()
+    }
 }
 
 macro_rules! create_config {
@@ -64,6 +78,9 @@ macro_rules! create_config {
             // if a license_template_path has been specified, successfully read, parsed and compiled
             // into a regex, it will be stored here
             pub license_template: Option<Regex>,
+            // the license text rendered from the template, if the template has no placeholders
+            // other than `{year}`; used to insert missing licenses
+            license_text: Option<String>,
             // For each config item, we store a bool indicating whether it has
             // been accessed and the value, and a bool whether the option was
             // manually initialised, or taken from the default,
@@ -106,6 +123,10 @@ macro_rules! create_config {
                     | "chain_width" => self.0.set_heuristics(),
                     "license_template_path" => self.0.set_license_template(),
                     "merge_imports" => self.0.set_merge_imports(),
+                    "style_edition" => {
+                        self.0.set_style_edition_defaults();
+                        self.0.set_heuristics();
+                    }
                     &_ => (),
                 }
             }
@@ -162,6 +183,7 @@ macro_rules! create_config {
                     }
                 }
             )+
+                self.set_style_edition_defaults();
                 self.set_heuristics();
                 self.set_license_template();
                 self.set_ignore(dir);
@@ -180,6 +202,7 @@ macro_rules! create_config {
             }
 
             pub(crate) fn is_valid_name(name: &str) -> bool {
+                let name = renamed_option(name).unwrap_or(name);
                 match name {
                     $(
                         stringify!($i) => true,
@@ -188,8 +211,30 @@ macro_rules! create_config {
                 }
             }
 
+            /// Describes why `value` can't be set for the option `key` in a config file, if it
+            /// can't.
+            fn check_toml_value(key: &str, value: &::toml::Value) -> Result<(), String> {
+                match key {
+                    $(
+                        stringify!($i) => {
+                            if !($stb || crate::is_nightly_channel!()) {
+                                return Err(format!(
+                                    "`{}` is unstable and can only be set on the nightly channel",
+                                    key
+                                ));
+                            }
+                            value.clone().try_into::<$ty>().map(|_| ()).map_err(|e| {
+                                format!("invalid value for `{}`: {}", key, e)
+                            })
+                        }
+                    )+
+                    _ => Err(format!("unknown configuration option `{}`", key)),
+                }
+            }
+
             #[allow(unreachable_pub)]
             pub fn is_valid_key_val(key: &str, val: &str) -> bool {
+                let key = renamed_option(key).unwrap_or(key);
                 match key {
                     $(
                         stringify!($i) => val.parse::<$ty>().is_ok(),
@@ -220,9 +265,26 @@ macro_rules! create_config {
                 }
             }
 
+            /// Returns the name and TOML-formatted value of every option whose
+            /// current value differs from its default.
+            #[allow(unreachable_pub)]
+            pub fn diff_from_default(&self) -> Vec<(&'static str, String)> {
+                let mut diff = Vec::new();
+                $(
+                    if !ConfigType::is_same_value(&self.$i.2, &$def) {
+                        let value = ::toml::Value::try_from(&self.$i.2)
+                            .map(|v| v.to_string())
+                            .unwrap_or_else(|_| format!("{:?}", self.$i.2));
+                        diff.push((stringify!($i), value));
+                    }
+                )+
+                diff
+            }
+
             #[allow(unreachable_pub)]
             pub fn override_value(&mut self, key: &str, val: &str)
             {
+                let key = canonical_option_name(key);
                 match key {
                     $(
                         stringify!($i) => {
@@ -249,6 +311,10 @@ macro_rules! create_config {
                     | "chain_width" => self.set_heuristics(),
                     "license_template_path" => self.set_license_template(),
                     "merge_imports" => self.set_merge_imports(),
+                    "style_edition" => {
+                        self.set_style_edition_defaults();
+                        self.set_heuristics();
+                    }
                     &_ => (),
                 }
             }
@@ -376,6 +442,25 @@ macro_rules! create_config {
                 self.single_line_if_else_max_width.2 = single_line_if_else_max_width;
             }
 
+            // Applies the defaults of the chosen style edition to the options which
+            // haven't been set explicitly.
+            fn set_style_edition_defaults(&mut self) {
+                for &(key, val) in self.style_edition.2.defaults() {
+                    match key {
+                        $(
+                            stringify!($i) if !self.$i.1 => {
+                                self.$i.2 = val.parse::<$ty>().unwrap_or_else(|_| {
+                                    panic!("Invalid style edition default for {} (\"{}\")",
+                                           stringify!($i),
+                                           val)
+                                });
+                            }
+                        )+
+                        _ => (),
+                    }
+                }
+            }
+
             fn set_heuristics(&mut self) {
                 let max_width = self.max_width.2;
                 match self.use_small_heuristics.2 {
@@ -391,12 +476,16 @@ macro_rules! create_config {
                     let lt_path = self.license_template_path();
                     if lt_path.len() > 0 {
                         match license::load_and_compile_template(&lt_path) {
-                            Ok(re) => self.license_template = Some(re),
+                            Ok((re, text)) => {
+                                self.license_template = Some(re);
+                                self.license_text = text;
+                            }
                             Err(msg) => eprintln!("Warning for license template file {:?}: {}",
                                                 lt_path, msg),
                         }
                     } else {
                         self.license_template = None;
+                        self.license_text = None;
                     }
                 }
             }
@@ -438,6 +527,7 @@ macro_rules! create_config {
             fn default() -> Config {
                 Config {
                     license_template: None,
+                    license_text: None,
                     $(
                         $i: (Cell::new(false), false, $def, $stb),
                     )+
@@ -2,6 +2,7 @@ use std::fmt;
 use std::fs::File;
 use std::io;
 use std::io::Read;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use regex;
 use regex::Regex;
@@ -49,6 +50,10 @@ use self::ParsingState::*;
 
 pub(crate) struct TemplateParser {
     parsed: String,
+    // the literal text of the license, if the template has no regex placeholders
+    // other than `{year}`
+    text: Option<String>,
+    year: String,
     buffer: String,
     state: ParsingState,
     linum: u32,
@@ -56,9 +61,11 @@ pub(crate) struct TemplateParser {
 }
 
 impl TemplateParser {
-    fn new() -> Self {
+    fn new(year: u64) -> Self {
         Self {
             parsed: "^".to_owned(),
+            text: Some(String::new()),
+            year: year.to_string(),
             buffer: String::new(),
             state: Lit,
             linum: 1,
@@ -75,6 +82,7 @@ impl TemplateParser {
     ///
     /// - to insert literal `{`, `}` or `\`, escape it with `\`
     /// - an empty regex placeholder (`{}`) is shorthand for `{.*?}`
+    /// - `{year}` matches any four digit year
     ///
     /// This function parses this input format and builds a properly escaped *string* representation
     /// of the equivalent regular expression. It **does not** however guarantee that the returned
@@ -95,8 +103,9 @@ impl TemplateParser {
     /// // <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
     /// // option. This file may not be copied, modified, or distributed
     /// // except according to those terms.
-    /// "
-    ///     ).unwrap(),
+    /// ",
+    ///         2021,
+    ///     ).unwrap().0,
     ///     r"^
     /// // Copyright \d+ The \} Rust \\ Project \{ Developers\. See the ([A-Z]+)
     /// // file at the top\-level directory of this distribution and at
@@ -110,8 +119,14 @@ impl TemplateParser {
     /// "
     /// );
     /// ```
-    pub(crate) fn parse(template: &str) -> Result<String, LicenseError> {
-        let mut parser = Self::new();
+    ///
+    /// If the template has no placeholders other than `{year}`, the literal text of the license,
+    /// with `{year}` replaced by `year`, is returned as well.
+    pub(crate) fn parse(
+        template: &str,
+        year: u64,
+    ) -> Result<(String, Option<String>), LicenseError> {
+        let mut parser = Self::new(year);
         for chr in template.chars() {
             if chr == '\n' {
                 parser.linum += 1;
@@ -143,7 +158,13 @@ impl TemplateParser {
         }
         parser.parsed.push_str(&regex::escape(&parser.buffer));
 
-        Ok(parser.parsed)
+        Ok((parser.parsed, parser.text))
+    }
+
+    fn push_text(&mut self, s: &str) {
+        if let Some(ref mut text) = self.text {
+            text.push_str(s);
+        }
     }
 
     fn trans_from_lit(&mut self, chr: char) -> ParsingState {
@@ -161,6 +182,7 @@ impl TemplateParser {
             '\\' => LitEsc,
             _ => {
                 self.buffer.push(chr);
+                self.push_text(chr.encode_utf8(&mut [0; 4]));
                 Lit
             }
         }
@@ -168,6 +190,7 @@ impl TemplateParser {
 
     fn trans_from_litesc(&mut self, chr: char) -> ParsingState {
         self.buffer.push(chr);
+        self.push_text(chr.encode_utf8(&mut [0; 4]));
         Lit
     }
 
@@ -183,8 +206,14 @@ impl TemplateParser {
                         // default regex for empty placeholder {}
                         if self.buffer.is_empty() {
                             self.parsed.push_str(".*?");
+                            self.text = None;
+                        } else if self.buffer == "year" {
+                            self.parsed.push_str(r"\d{4}");
+                            let year = self.year.clone();
+                            self.push_text(&year);
                         } else {
                             self.parsed.push_str(&self.buffer);
+                            self.text = None;
                         }
                         self.buffer.clear();
                         Lit
@@ -212,55 +241,91 @@ impl TemplateParser {
     }
 }
 
-pub(crate) fn load_and_compile_template(path: &str) -> Result<Regex, LicenseError> {
+/// Loads the license template at `path`, returning the regex matching it and, if the template
+/// has no placeholders other than `{year}`, the text of the license for the current year.
+pub(crate) fn load_and_compile_template(
+    path: &str,
+) -> Result<(Regex, Option<String>), LicenseError> {
     let mut lt_file = File::open(&path)?;
     let mut lt_str = String::new();
     lt_file.read_to_string(&mut lt_str)?;
-    let lt_parsed = TemplateParser::parse(&lt_str)?;
-    Ok(Regex::new(&lt_parsed)?)
+    let (lt_parsed, lt_text) = TemplateParser::parse(&lt_str, current_year())?;
+    Ok((Regex::new(&lt_parsed)?, lt_text))
+}
+
+/// Returns the current year in UTC.
+fn current_year() -> u64 {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    // See http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let days = secs / 86_400 + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    // The year computed so far starts in March, so January and February belong to the next one.
+    let year = year_of_era + era * 400;
+    if month_index >= 10 { year + 1 } else { year }
 }
 
 #[cfg(test)]
 mod test {
-    use super::TemplateParser;
+    use super::{current_year, LicenseError, TemplateParser};
+
+    fn parse(template: &str) -> Result<String, LicenseError> {
+        TemplateParser::parse(template, 2021).map(|(parsed, _)| parsed)
+    }
 
     #[test]
     fn test_parse_license_template() {
+        assert_eq!(parse("literal (.*)").unwrap(), r"^literal \(\.\*\)");
+        assert_eq!(parse(r"escaping \}").unwrap(), r"^escaping \}");
+        assert!(parse("unbalanced } without escape").is_err());
         assert_eq!(
-            TemplateParser::parse("literal (.*)").unwrap(),
-            r"^literal \(\.\*\)"
-        );
-        assert_eq!(
-            TemplateParser::parse(r"escaping \}").unwrap(),
-            r"^escaping \}"
-        );
-        assert!(TemplateParser::parse("unbalanced } without escape").is_err());
-        assert_eq!(
-            TemplateParser::parse(r"{\d+} place{-?}holder{s?}").unwrap(),
+            parse(r"{\d+} place{-?}holder{s?}").unwrap(),
             r"^\d+ place-?holders?"
         );
-        assert_eq!(TemplateParser::parse("default {}").unwrap(), "^default .*?");
+        assert_eq!(parse("default {}").unwrap(), "^default .*?");
         assert_eq!(
-            TemplateParser::parse(r"unbalanced nested braces {\{{3}}").unwrap(),
+            parse(r"unbalanced nested braces {\{{3}}").unwrap(),
             r"^unbalanced nested braces \{{3}"
         );
         assert_eq!(
-            &TemplateParser::parse("parsing error }")
-                .unwrap_err()
-                .to_string(),
+            &parse("parsing error }").unwrap_err().to_string(),
             "parsing failed, escape or balance closing brace on l. 1"
         );
         assert_eq!(
-            &TemplateParser::parse("parsing error {\nsecond line")
+            &parse("parsing error {\nsecond line")
                 .unwrap_err()
                 .to_string(),
             "parsing failed, escape or balance opening brace on l. 1"
         );
         assert_eq!(
-            &TemplateParser::parse(r"parsing error \")
-                .unwrap_err()
-                .to_string(),
+            &parse(r"parsing error \").unwrap_err().to_string(),
             "parsing failed, incomplete escape sequence on l. 1"
         );
     }
+
+    #[test]
+    fn test_parse_license_text() {
+        assert_eq!(
+            TemplateParser::parse(r"// Copyright {year} \{Foo\}", 2021).unwrap(),
+            (
+                r"^// Copyright \d{4} \{Foo\}".to_owned(),
+                Some("// Copyright 2021 {Foo}".to_owned())
+            )
+        );
+        assert_eq!(
+            TemplateParser::parse(r"// Copyright {\d+} Foo", 2021).unwrap(),
+            (r"^// Copyright \d+ Foo".to_owned(), None)
+        );
+        assert_eq!(
+            TemplateParser::parse("// {} Foo", 2021).unwrap(),
+            (r"^// .*? Foo".to_owned(), None)
+        );
+        assert!(current_year() >= 2021);
+    }
 }
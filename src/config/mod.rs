@@ -1,8 +1,11 @@
 use std::cell::Cell;
+use std::collections::{HashMap, HashSet};
 use std::default::Default;
 use std::fs::File;
 use std::io::{Error, ErrorKind, Read};
 use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::SystemTime;
 use std::{env, fs};
 
 use regex::Regex;
@@ -34,7 +37,10 @@ create_config! {
     max_width: usize, 100, true, "Maximum width of each line";
     hard_tabs: bool, false, true, "Use tab characters for indentation, spaces for alignment";
     tab_spaces: usize, 4, true, "Number of spaces per tab";
+    continuation_indent_width: usize, 0, false,
+        "Number of spaces to indent wrapped expressions and chains; 0 uses tab_spaces";
     newline_style: NewlineStyle, NewlineStyle::Auto, true, "Unix or Windows line endings";
+    newline_at_eof: bool, true, false, "End files with a newline";
     indent_style: IndentStyle, IndentStyle::Block, false, "How do we indent expressions or items";
 
     // Width Heuristics
@@ -55,28 +61,58 @@ create_config! {
     chain_width: usize, 60, true, "Maximum length of a chain to fit on a single line.";
     single_line_if_else_max_width: usize, 50, true, "Maximum line length for single line if-else \
         expressions. A value of zero means always break if-else expressions.";
+    short_array_element_width_threshold: usize, 10, false,
+        "Maximum width of an array element for the elements to be packed several per line";
 
     // Comments. macros, and strings
     wrap_comments: bool, false, false, "Break comments to fit on the line";
     format_code_in_doc_comments: bool, false, false, "Format the code snippet in doc comments.";
+    doc_comment_code_block_width: usize, 100, false, "Maximum width of the code snippets in \
+        doc comments, including the comment prefix and the indentation of the comment";
     comment_width: usize, 80, false,
         "Maximum length of comments. No effect unless wrap_comments = true";
     normalize_comments: bool, false, false, "Convert /* */ comments to // comments where possible";
     normalize_doc_attributes: bool, false, false, "Normalize doc attributes as doc comments";
+    preserve_block_comment_indent: bool, false, false,
+        "Keep the layout of block comments whose lines don't start with `*`";
+    preserve_inner_doc_comments: bool, false, false,
+        "Leave `//!` inner doc comments as written instead of normalizing or wrapping them";
+    trim_comment_trailing_whitespace: bool, true, false,
+        "Remove trailing whitespace from the lines of comments";
     license_template_path: String, String::default(), false,
         "Beginning of file must match license template";
+    insert_license: bool, false, false,
+        "Insert the license from license_template_path into files which lack one";
     format_strings: bool, false, false, "Format string literals where necessary";
+    string_lit_width: usize, 0, false,
+        "Width below which format_strings leaves a string literal unchanged";
+    string_wrap_indent: IndentStyle, IndentStyle::Visual, false,
+        "Indent style of the continuation lines of a reflowed string literal";
+    normalize_numeric_literals: bool, false, false,
+        "Lowercase the radix prefix, hex digits and exponent marker of numeric literals";
+    hex_literal_case: HexLiteralCase, HexLiteralCase::Preserve, false,
+        "Format the digits of hex literals as uppercase or lowercase";
     format_macro_matchers: bool, false, false,
         "Format the metavariable matching patterns in macros";
     format_macro_bodies: bool, true, false, "Format the bodies of macros";
 
     // Single line expressions and items
     empty_item_single_line: bool, true, false,
-        "Put empty-body functions and impls on a single line";
+        "Put empty-body items on a single line";
+    fn_empty_single_line: bool, true, false, "Put empty-body functions on a single line. \
+        Follows `empty_item_single_line` when unset";
+    impl_empty_single_line: bool, true, false, "Put empty-body impls on a single line. \
+        Follows `empty_item_single_line` when unset";
     struct_lit_single_line: bool, true, false,
         "Put small struct literals on a single line";
+    struct_lit_single_line_width: usize, 0, false, "Maximum width of a whole struct literal, \
+        including its path and braces, to be put on a single line. 0 means no limit";
     fn_single_line: bool, false, false, "Put single-expression functions on a single line";
     where_single_line: bool, false, false, "Force where-clauses to be on a single line";
+    where_bound_layout: WhereBoundLayout, WhereBoundLayout::Front, false,
+        "Layout of the bounds within a single where-clause predicate";
+    trait_bound_layout: SeparatorPlace, SeparatorPlace::Front, false,
+        "Where to put the `+` when the supertraits of a trait are put one per line";
 
     // Imports
     imports_indent: IndentStyle, IndentStyle::Block, false, "Indent of imports";
@@ -86,6 +122,7 @@ create_config! {
     group_imports: GroupImportsTactic, GroupImportsTactic::Preserve, false,
         "Controls the strategy for how imports are grouped together";
     merge_imports: bool, false, false, "(deprecated: use imports_granularity instead)";
+    normalize_imports_self: bool, false, false, "Rewrite `foo::{self}` imports to `foo`";
 
     // Ordering
     reorder_imports: bool, true, true, "Reorder import and extern crate statements alphabetically";
@@ -98,6 +135,10 @@ create_config! {
     space_before_colon: bool, false, false, "Leave a space before the colon";
     space_after_colon: bool, true, false, "Leave a space after the colon";
     spaces_around_ranges: bool, false, false, "Put spaces around the  .. and ..= range operators";
+    space_after_macro_bang: bool, false, false,
+        "Leave a space between the `!` of a macro call and its `(` or `[` delimiter";
+    space_around_attr_eq: bool, true, false,
+        "Put spaces around the `=` of key-value pairs in attributes";
     binop_separator: SeparatorPlace, SeparatorPlace::Front, false,
         "Where to put a binary operator when a binary expression goes multiline";
 
@@ -114,15 +155,29 @@ create_config! {
         the same line with the pattern of arms";
     match_arm_leading_pipes: MatchArmLeadingPipe, MatchArmLeadingPipe::Never, true,
         "Determines whether leading pipes are emitted on match arms";
+    match_pattern_separator_break_point: MatchPatternBreak, MatchPatternBreak::Before, false,
+        "Where to put the `|` separators when an or-pattern is broken over several lines, \
+         follows binop_separator if unset";
+    blank_lines_between_match_arms: usize, 1, false,
+        "Maximum number of blank lines which can be kept between match arms";
     force_multiline_blocks: bool, false, false,
         "Force multiline closure bodies and match arms to be wrapped in a block";
-    fn_args_layout: Density, Density::Tall, true,
-        "Control the layout of arguments in a function";
+    closure_body_braces: bool, false, false,
+        "Always wrap the body of a closure in a block when it doesn't fit on one line";
+    fn_params_layout: Density, Density::Tall, true,
+        "Control the layout of parameters in a function signature";
+    fn_generics_space: FnGenericsSpace, FnGenericsSpace::None, false,
+        "Spaces around the generic parameters of a function signature";
+    space_before_fn_paren: bool, false, false,
+        "Put a space before the parameter list of a function definition";
     brace_style: BraceStyle, BraceStyle::SameLineWhere, false, "Brace style for items";
     control_brace_style: ControlBraceStyle, ControlBraceStyle::AlwaysSameLine, false,
         "Brace style for control flow constructs";
     trailing_semicolon: bool, true, false,
-        "Add trailing semicolon after break, continue and return";
+        "Add trailing semicolon after break, continue, return and unit block-like statements";
+    strip_tail_return: bool, false, false,
+        "Replace a `return expr;` at the end of a function or closure body with the tail \
+         expression `expr`";
     trailing_comma: SeparatorTactic, SeparatorTactic::Vertical, false,
         "How to handle trailing commas for lists";
     match_block_trailing_comma: bool, false, false,
@@ -133,12 +188,16 @@ create_config! {
         "Minimum number of blank lines which must be put between items";
     edition: Edition, Edition::Edition2015, true, "The edition of the parser (RFC 2052)";
     version: Version, Version::One, false, "Version of formatting rules";
+    style_edition: StyleEdition, StyleEdition::Edition2015, false,
+        "The edition whose defaults are used for the options which aren't set explicitly";
     inline_attribute_width: usize, 0, false,
         "Write an item and its attribute on the same line \
         if their combined width is below a threshold";
 
     // Options that can change the source code beyond whitespace/blocks (somewhat linty things)
     merge_derives: bool, true, true, "Merge multiple `#[derive(...)]` into a single one";
+    derive_trailing_comma: bool, true, false,
+        "Put a trailing comma after the last item of a derive list wrapped one item per line";
     use_try_shorthand: bool, false, true, "Replace uses of the try! macro by the ? shorthand";
     use_field_init_shorthand: bool, false, true, "Use field initialization shorthand if possible";
     force_explicit_abi: bool, true, true, "Always print the abi for extern items";
@@ -148,14 +207,24 @@ create_config! {
     // Control options (changes the operation of rustfmt, rather than the formatting)
     color: Color, Color::Auto, false,
         "What Color option to use when none is supplied: Always, Never, Auto";
+    verbose_diff: bool, false, false,
+        "Print the diffs of --check as unified diffs which can be applied with patch";
+    diff_context_lines: usize, 3, false,
+        "Number of unchanged lines shown around each change in the diffs of --check";
     required_version: String, env!("CARGO_PKG_VERSION").to_owned(), false,
         "Require a specific version of rustfmt";
     unstable_features: bool, false, false,
             "Enables unstable features. Only available on nightly channel";
     disable_all_formatting: bool, false, false, "Don't reformat anything";
+    format_generated_files: bool, true, false,
+        "Format files whose leading comments contain the `@generated` marker";
     skip_children: bool, false, false, "Don't reformat out of line modules";
     hide_parse_errors: bool, false, false, "Hide errors from the parser";
+    error_emission: ErrorEmission, ErrorEmission::Human, false,
+        "How to report errors from the parser";
     error_on_line_overflow: bool, false, false, "Error if unable to get all lines within max_width";
+    error_on_line_overflow_comments: bool, true, false,
+        "Include comments in the error_on_line_overflow check";
     error_on_unformatted: bool, false, false,
         "Error if unable to get comments or string literals within max_width, \
          or they are left with trailing whitespaces";
@@ -163,6 +232,10 @@ create_config! {
         "Report all, none or unnumbered occurrences of TODO in source file comments";
     report_fixme: ReportTactic, ReportTactic::Never, false,
         "Report all, none or unnumbered occurrences of FIXME in source file comments";
+    report_unsafe_without_safety_comment: ReportTactic, ReportTactic::Never, false,
+        "Report unsafe blocks and functions which are not preceded by a SAFETY comment";
+    report_unreachable_match_arms: ReportTactic, ReportTactic::Never, false,
+        "Report match arms which follow a catch-all arm";
     ignore: IgnoreList, IgnoreList::default(), false,
         "Skip formatting the specified files and directories";
 
@@ -174,9 +247,14 @@ create_config! {
     emit_mode: EmitMode, EmitMode::Files, false,
         "What emit Mode to use when none is supplied";
     make_backup: bool, false, false, "Backup changed files";
+    backup_suffix: String, String::from("bk"), false,
+        "Extension given to the backups of changed files";
     print_misformatted_file_names: bool, false, true,
         "Prints the names of mismatched files that were formatted. Prints the names of \
          files that would be formated when used with `--check` mode. ";
+    null_separated_file_names: bool, false, false,
+        "Terminate the file names printed by `--emit files-with-diff` with NUL instead of \
+         a newline";
 }
 
 #[derive(Error, Debug)]
@@ -184,6 +262,55 @@ create_config! {
 pub struct ToTomlError(toml::ser::Error);
 
 impl PartialConfig {
+    /// Returns a description of each option whose value conflicts with another option.
+    pub(crate) fn validate(&self) -> Vec<String> {
+        let mut problems = vec![];
+        let max_width = self
+            .max_width
+            .unwrap_or_else(|| Config::default().max_width());
+        let widths = [
+            ("fn_call_width", self.fn_call_width),
+            ("attr_fn_like_width", self.attr_fn_like_width),
+            ("struct_lit_width", self.struct_lit_width),
+            ("struct_variant_width", self.struct_variant_width),
+            ("array_width", self.array_width),
+            ("chain_width", self.chain_width),
+            (
+                "single_line_if_else_max_width",
+                self.single_line_if_else_max_width,
+            ),
+        ];
+        for &(name, width) in widths.iter() {
+            match width {
+                Some(width) if width > max_width => problems.push(format!(
+                    "`{}` ({}) cannot exceed `max_width` ({})",
+                    name, width, max_width
+                )),
+                _ => {}
+            }
+        }
+        if self.merge_imports.is_some() && self.imports_granularity.is_some() {
+            problems.push(String::from(
+                "`merge_imports` is deprecated and ignored when `imports_granularity` is set",
+            ));
+        }
+        if let Some(ref backup_suffix) = self.backup_suffix {
+            if !is_valid_backup_suffix(backup_suffix) {
+                problems.push(format!("invalid `backup_suffix`: `{}`", backup_suffix));
+            }
+        }
+        if let Some(ref required_version) = self.required_version {
+            let version = env!("CARGO_PKG_VERSION");
+            if required_version != version {
+                problems.push(format!(
+                    "rustfmt version ({}) doesn't match the required version ({})",
+                    version, required_version
+                ));
+            }
+        }
+        problems
+    }
+
     pub fn to_toml(&self) -> Result<String, ToTomlError> {
         // Non-user-facing options can't be specified in TOML
         let mut cloned = self.clone();
@@ -191,13 +318,146 @@ impl PartialConfig {
         cloned.verbose = None;
         cloned.width_heuristics = None;
         cloned.print_misformatted_file_names = None;
+        cloned.null_separated_file_names = None;
         cloned.merge_imports = None;
 
         ::toml::to_string(&cloned).map_err(ToTomlError)
     }
 }
 
+/// Options which have been renamed, as `(old name, new name)` pairs. The old names are still
+/// accepted wherever an option can be set.
+const RENAMED_OPTIONS: [(&str, &str); 3] = [
+    ("fn_args_layout", "fn_params_layout"),
+    ("normalise_comments", "normalize_comments"),
+    ("normalise_doc_attributes", "normalize_doc_attributes"),
+];
+
+/// Returns the new name of `name` if it is the old name of a renamed option.
+fn renamed_option(name: &str) -> Option<&'static str> {
+    RENAMED_OPTIONS
+        .iter()
+        .find(|(old, _)| *old == name)
+        .map(|&(_, new)| new)
+}
+
+/// Returns `true` if `suffix` can be used as the extension of a backup file, which must stay
+/// in the same directory as the file it backs up. `rs` would make the backup the formatted file
+/// itself, and `tmp` is the extension of the file the formatted text is first written to.
+pub fn is_valid_backup_suffix(suffix: &str) -> bool {
+    let suffix = suffix.trim_start_matches('.');
+    !suffix.is_empty()
+        && !suffix.contains(std::path::is_separator)
+        && !matches!(suffix, "rs" | "tmp")
+}
+
+/// Returns the current name of the option `name`, printing a deprecation warning the first time
+/// each old name is used.
+fn canonical_option_name(name: &str) -> &str {
+    lazy_static! {
+        static ref WARNED: Mutex<HashSet<String>> = Mutex::new(HashSet::new());
+    }
+    match renamed_option(name) {
+        Some(new) => {
+            if WARNED.lock().unwrap().insert(name.to_owned()) {
+                eprintln!(
+                    "Warning: the `{}` option is deprecated. Use `{}` instead",
+                    name, new
+                );
+            }
+            new
+        }
+        None => name,
+    }
+}
+
+/// Directives read by rustfmt's test suite which are not configuration options.
+const TEST_DIRECTIVES: [&str; 3] = ["config", "target", "unstable"];
+
+/// Returns the key and value of each `// rustfmt-<key>: <value>` directive at the
+/// start of `src`. Only the leading block of directives and blank lines is scanned.
+pub(crate) fn file_directives(src: &str) -> Vec<(&str, &str)> {
+    fn parse_directive(line: &str) -> Option<(&str, &str)> {
+        let directive = line.trim_start().strip_prefix("//")?;
+        let directive = directive.trim_start().strip_prefix("rustfmt-")?;
+        let colon = directive.find(':')?;
+        let key = &directive[..colon];
+        let val = directive[colon + 1..].split_whitespace().next()?;
+        Some((key, val))
+    }
+
+    src.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(parse_directive)
+        .take_while(Option::is_some)
+        .flatten()
+        .collect()
+}
+
 impl Config {
+    /// The width by which wrapped expressions and chain elements are indented.
+    /// Falls back to `tab_spaces` when `continuation_indent_width` is unset or
+    /// hard tabs are used.
+    pub(crate) fn continuation_indent_width_or_default(&self) -> usize {
+        match self.continuation_indent_width() {
+            0 => self.tab_spaces(),
+            _ if self.hard_tabs() => self.tab_spaces(),
+            width => width,
+        }
+    }
+
+    /// Whether empty-body functions are put on a single line. Follows
+    /// `empty_item_single_line` unless `fn_empty_single_line` is set.
+    pub(crate) fn fn_empty_single_line_or_default(&self) -> bool {
+        if self.was_set().fn_empty_single_line() {
+            self.fn_empty_single_line()
+        } else {
+            self.empty_item_single_line()
+        }
+    }
+
+    /// Whether empty-body impls are put on a single line. Follows
+    /// `empty_item_single_line` unless `impl_empty_single_line` is set.
+    pub(crate) fn impl_empty_single_line_or_default(&self) -> bool {
+        if self.was_set().impl_empty_single_line() {
+            self.impl_empty_single_line()
+        } else {
+            self.empty_item_single_line()
+        }
+    }
+
+    /// The license text rendered from `license_template_path`, if the template
+    /// can be turned into text.
+    pub(crate) fn license_text(&self) -> Option<&str> {
+        self.license_text.as_deref()
+    }
+
+    /// The old name of `fn_params_layout`.
+    #[allow(unreachable_pub)]
+    #[deprecated(note = "use `fn_params_layout` instead")]
+    pub fn fn_args_layout(&self) -> Density {
+        self.fn_params_layout()
+    }
+
+    /// Returns a copy of this config with the `// rustfmt-<option>: <value>`
+    /// directives at the start of `src` applied on top of it.
+    pub(crate) fn with_file_directives(&self, src: &str) -> Result<Config, String> {
+        let mut config = self.clone();
+        for (key, val) in file_directives(src) {
+            if TEST_DIRECTIVES.contains(&key) {
+                continue;
+            }
+            if !Config::is_valid_name(key) {
+                return Err(format!("unknown configuration option `{}`", key));
+            }
+            if !Config::is_valid_key_val(key, val) {
+                return Err(format!("invalid value `{}` for option `{}`", val, key));
+            }
+            config.override_value(key, val);
+        }
+        Ok(config)
+    }
+
     pub(crate) fn version_meets_requirement(&self) -> bool {
         if self.was_set().required_version() {
             let version = env!("CARGO_PKG_VERSION");
@@ -222,10 +482,28 @@ impl Config {
     /// Returns a `Config` if the config could be read and parsed from
     /// the file, otherwise errors.
     pub(super) fn from_toml_path(file_path: &Path) -> Result<Config, Error> {
+        Config::from_toml_path_extending(file_path, &mut vec![])
+    }
+
+    // `visited` collects the files of the `extends` chain, in order to detect cycles. As a
+    // config file extends at most one other file, these are all still being loaded.
+    fn from_toml_path_extending(
+        file_path: &Path,
+        visited: &mut Vec<PathBuf>,
+    ) -> Result<Config, Error> {
+        let canonical_path = fs::canonicalize(file_path)?;
+        if visited.contains(&canonical_path) {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("Cyclic `extends` in config file {}", file_path.display()),
+            ));
+        }
+        visited.push(canonical_path);
+
         let mut file = File::open(&file_path)?;
         let mut toml = String::new();
         file.read_to_string(&mut toml)?;
-        Config::from_toml(&toml, file_path.parent().unwrap())
+        Config::from_toml_extending(&toml, file_path.parent().unwrap(), visited)
             .map_err(|err| Error::new(ErrorKind::InvalidData, err))
     }
 
@@ -239,63 +517,78 @@ impl Config {
     /// Returns the `Config` to use, and the path of the project file if there was
     /// one.
     pub(super) fn from_resolved_toml_path(dir: &Path) -> Result<(Config, Option<PathBuf>), Error> {
-        /// Try to find a project file in the given directory and its parents.
-        /// Returns the path of a the nearest project file if one exists,
-        /// or `None` if no project file was found.
-        fn resolve_project_file(dir: &Path) -> Result<Option<PathBuf>, Error> {
-            let mut current = if dir.is_relative() {
-                env::current_dir()?.join(dir)
-            } else {
-                dir.to_path_buf()
-            };
-
-            current = fs::canonicalize(current)?;
-
-            loop {
-                match get_toml_path(&current) {
-                    Ok(Some(path)) => return Ok(Some(path)),
-                    Err(e) => return Err(e),
-                    _ => (),
-                }
-
-                // If the current directory has no parent, we're done searching.
-                if !current.pop() {
-                    break;
-                }
-            }
+        match resolve_project_file(dir)? {
+            None => Ok((Config::default(), None)),
+            Some(path) => Config::from_cached_toml_path(&path).map(|config| (config, Some(path))),
+        }
+    }
 
-            // If nothing was found, check in the home directory.
-            if let Some(home_dir) = dirs::home_dir() {
-                if let Some(path) = get_toml_path(&home_dir)? {
-                    return Ok(Some(path));
-                }
-            }
+    /// Like `from_toml_path`, but reuses the config parsed from `file_path` earlier in this
+    /// process, since the input files in a directory tree usually share a config file. The
+    /// cached config is dropped once `file_path`, or any file it `extends`, is modified.
+    fn from_cached_toml_path(file_path: &Path) -> Result<Config, Error> {
+        lazy_static! {
+            static ref CACHE: Mutex<HashMap<PathBuf, (Vec<(PathBuf, SystemTime)>, Config)>> =
+                Mutex::new(HashMap::new());
+        }
+        fn modified(path: &Path) -> Option<SystemTime> {
+            fs::metadata(path)
+                .and_then(|metadata| metadata.modified())
+                .ok()
+        }
 
-            // If none was found ther either, check in the user's configuration directory.
-            if let Some(mut config_dir) = dirs::config_dir() {
-                config_dir.push("rustfmt");
-                if let Some(path) = get_toml_path(&config_dir)? {
-                    return Ok(Some(path));
-                }
+        if let Some((files, config)) = CACHE.lock().unwrap().get(file_path) {
+            if files.iter().all(|(path, time)| modified(path) == Some(*time)) {
+                return Ok(config.clone());
             }
-
-            Ok(None)
         }
-
-        match resolve_project_file(dir)? {
-            None => Ok((Config::default(), None)),
-            Some(path) => Config::from_toml_path(&path).map(|config| (config, Some(path))),
+        let mut files = vec![];
+        let config = Config::from_toml_path_extending(file_path, &mut files)?;
+        let modified_times = files
+            .into_iter()
+            .map(|path| modified(&path).map(|time| (path, time)))
+            .collect::<Option<Vec<_>>>();
+        // Without the modification times of all the files, a cached config could be stale.
+        if let Some(modified_times) = modified_times {
+            CACHE
+                .lock()
+                .unwrap()
+                .insert(file_path.to_owned(), (modified_times, config.clone()));
         }
+        Ok(config)
     }
 
     pub(crate) fn from_toml(toml: &str, dir: &Path) -> Result<Config, String> {
-        let parsed: ::toml::Value = toml
+        Config::from_toml_extending(toml, dir, &mut vec![])
+    }
+
+    /// Parses `toml` on top of the config file named by its `extends` key, if any.
+    /// A relative `extends` path is resolved against `dir`.
+    fn from_toml_extending(
+        toml: &str,
+        dir: &Path,
+        visited: &mut Vec<PathBuf>,
+    ) -> Result<Config, String> {
+        let mut parsed: ::toml::Value = toml
             .parse()
             .map_err(|e| format!("Could not parse TOML: {}", e))?;
         let mut err = String::new();
         let table = parsed
-            .as_table()
+            .as_table_mut()
             .ok_or_else(|| String::from("Parsed config was not table"))?;
+        let base_config = match table.remove("extends") {
+            Some(::toml::Value::String(base_path)) => {
+                Config::from_toml_path_extending(&dir.join(&base_path), visited).map_err(|e| {
+                    format!(
+                        "Could not load the config extended by `{}`: {}",
+                        base_path, e
+                    )
+                })?
+            }
+            Some(_) => return Err(String::from("`extends` must be the path to a config file")),
+            None => Config::default(),
+        };
+        rename_options(table);
         for key in table.keys() {
             if !Config::is_valid_name(key) {
                 let msg = &format!("Warning: Unknown configuration option `{}`\n", key);
@@ -307,7 +600,7 @@ impl Config {
                 if !err.is_empty() {
                     eprint!("{}", err);
                 }
-                Ok(Config::default().fill_from_parsed_config(parsed_config, dir))
+                Ok(base_config.fill_from_parsed_config(parsed_config, dir))
             }
             Err(e) => {
                 err.push_str("Error: Decoding config file failed:\n");
@@ -317,6 +610,85 @@ impl Config {
             }
         }
     }
+
+    /// Checks the config file at `path`, or the config file in the directory `path`, without
+    /// formatting anything. Returns a description of each problem found: unknown options, values
+    /// of the wrong type, unstable options set on the stable channel and options which conflict
+    /// with each other.
+    pub fn check_toml_path(path: &Path) -> Result<Vec<String>, Error> {
+        let file_path = if path.is_dir() {
+            get_toml_path(path)?.ok_or_else(|| {
+                Error::new(
+                    ErrorKind::NotFound,
+                    format!("No config file found in {}", path.display()),
+                )
+            })?
+        } else {
+            path.to_path_buf()
+        };
+        let toml = fs::read_to_string(&file_path)?;
+        Ok(Config::check_toml(&toml, file_path.parent().unwrap()))
+    }
+
+    fn check_toml(toml: &str, dir: &Path) -> Vec<String> {
+        let mut parsed: ::toml::Value = match toml.parse() {
+            Ok(parsed) => parsed,
+            Err(e) => return vec![format!("could not parse TOML: {}", e)],
+        };
+        let table = match parsed.as_table_mut() {
+            Some(table) => table,
+            None => return vec![String::from("parsed config was not a table")],
+        };
+        let mut problems = vec![];
+        match table.remove("extends") {
+            Some(::toml::Value::String(base_path)) => {
+                if let Err(e) = Config::from_toml_path(&dir.join(&base_path)) {
+                    problems.push(format!(
+                        "could not load the config extended by `{}`: {}",
+                        base_path, e
+                    ));
+                }
+            }
+            Some(_) => problems.push(String::from("`extends` must be the path to a config file")),
+            None => {}
+        }
+        rename_options(table);
+        for (key, value) in table.iter() {
+            if let Err(e) = Config::check_toml_value(key, value) {
+                problems.push(e);
+            }
+        }
+        // Conflicts can only be looked for once every value has the right type.
+        if problems.is_empty() {
+            match parsed.try_into::<PartialConfig>() {
+                Ok(parsed_config) => problems.extend(parsed_config.validate()),
+                Err(e) => problems.push(e.to_string()),
+            }
+        }
+        problems
+    }
+}
+
+impl ConfigSetter<'_> {
+    /// The old name of `fn_params_layout`.
+    #[allow(unreachable_pub)]
+    #[deprecated(note = "use `fn_params_layout` instead")]
+    pub fn fn_args_layout(&mut self, value: Density) {
+        self.fn_params_layout(value)
+    }
+}
+
+/// Replaces the old names of renamed options in `table` with their new names.
+fn rename_options(table: &mut ::toml::value::Table) {
+    let keys: Vec<String> = table.keys().cloned().collect();
+    for key in keys {
+        let name = canonical_option_name(&key);
+        if name != key {
+            let value = table.remove(&key).unwrap();
+            // The new name takes precedence when both are given.
+            table.entry(name).or_insert(value);
+        }
+    }
 }
 
 /// Loads a config by checking the client-supplied options and if appropriate, the
@@ -370,6 +742,49 @@ fn get_toml_path(dir: &Path) -> Result<Option<PathBuf>, Error> {
     Ok(None)
 }
 
+/// Tries to find a project file in the given directory and its parents.
+/// Returns the path of the nearest project file if one exists,
+/// or `None` if no project file was found.
+fn resolve_project_file(dir: &Path) -> Result<Option<PathBuf>, Error> {
+    let mut current = if dir.is_relative() {
+        env::current_dir()?.join(dir)
+    } else {
+        dir.to_path_buf()
+    };
+
+    current = fs::canonicalize(current)?;
+
+    loop {
+        match get_toml_path(&current) {
+            Ok(Some(path)) => return Ok(Some(path)),
+            Err(e) => return Err(e),
+            _ => (),
+        }
+
+        // If the current directory has no parent, we're done searching.
+        if !current.pop() {
+            break;
+        }
+    }
+
+    // If nothing was found, check in the home directory.
+    if let Some(home_dir) = dirs::home_dir() {
+        if let Some(path) = get_toml_path(&home_dir)? {
+            return Ok(Some(path));
+        }
+    }
+
+    // If none was found ther either, check in the user's configuration directory.
+    if let Some(mut config_dir) = dirs::config_dir() {
+        config_dir.push("rustfmt");
+        if let Some(path) = get_toml_path(&config_dir)? {
+            return Ok(Some(path));
+        }
+    }
+
+    Ok(None)
+}
+
 fn config_path(options: &dyn CliOptions) -> Result<Option<PathBuf>, Error> {
     let config_path_not_found = |path: &str| -> Result<Option<PathBuf>, Error> {
         Err(Error::new(
@@ -381,19 +796,20 @@ fn config_path(options: &dyn CliOptions) -> Result<Option<PathBuf>, Error> {
         ))
     };
 
-    // Read the config_path and convert to parent dir if a file is provided.
-    // If a config file cannot be found from the given path, return error.
+    // Search the given directory and its parents for a config file, or use the given file
+    // directly. If a config file cannot be found from the given path, return error.
     match options.config_path() {
         Some(path) if !path.exists() => config_path_not_found(path.to_str().unwrap()),
         Some(path) if path.is_dir() => {
-            let config_file_path = get_toml_path(path)?;
+            let config_file_path = resolve_project_file(path)?;
             if config_file_path.is_some() {
                 Ok(config_file_path)
             } else {
                 config_path_not_found(path.to_str().unwrap())
             }
         }
-        path => Ok(path.map(ToOwned::to_owned)),
+        Some(path) => Ok(Some(fs::canonicalize(path)?)),
+        None => Ok(None),
     }
 }
 
@@ -446,6 +862,8 @@ mod test {
             chain_width: usize, 60, true, "Maximum length of a chain to fit on a single line.";
             single_line_if_else_max_width: usize, 50, true, "Maximum line length for single \
                 line if-else expressions. A value of zero means always break if-else expressions.";
+            style_edition: StyleEdition, StyleEdition::Edition2015, false,
+                "The edition whose defaults are used for the options which aren't set explicitly";
 
             // Options that are used by the tests
             stable_option: bool, false, true, "A stable option";
@@ -488,6 +906,80 @@ mod test {
         assert_eq!(config.was_set().verbose(), false);
     }
 
+    #[test]
+    fn test_diff_from_default() {
+        assert!(Config::default().diff_from_default().is_empty());
+
+        let toml = "tab_spaces = 2\nbrace_style = \"AlwaysNextLine\"\nhard_tabs = false";
+        let config = Config::from_toml(toml, Path::new("")).unwrap();
+        assert_eq!(
+            config.diff_from_default(),
+            vec![
+                ("tab_spaces", "2".to_owned()),
+                ("brace_style", "\"AlwaysNextLine\"".to_owned()),
+            ]
+        );
+
+        // The directory of the config file alone doesn't make `ignore` differ.
+        let config = Config::from_toml("tab_spaces = 2", Path::new("tests/config")).unwrap();
+        assert_eq!(
+            config.diff_from_default(),
+            vec![("tab_spaces", "2".to_owned())]
+        );
+
+        let toml = "ignore = [\"foo.rs\"]";
+        let config = Config::from_toml(toml, Path::new("tests/config")).unwrap();
+        assert_eq!(
+            config.diff_from_default(),
+            vec![("ignore", "[\"foo.rs\"]".to_owned())]
+        );
+    }
+
+    #[test]
+    fn test_file_directives() {
+        let src = "// rustfmt-max_width: 80\n\n//rustfmt-hard_tabs: true trailing\n\
+                   // rustfmt-tab_spaces: 2\nfn main() {}\n// rustfmt-edition: 2018\n";
+        assert_eq!(
+            file_directives(src),
+            vec![
+                ("max_width", "80"),
+                ("hard_tabs", "true"),
+                ("tab_spaces", "2")
+            ]
+        );
+        assert!(file_directives("fn main() {}\n").is_empty());
+    }
+
+    #[test]
+    fn test_with_file_directives() {
+        let config = Config::from_toml("max_width = 90", Path::new("")).unwrap();
+        let src = "// rustfmt-max_width: 80\n// rustfmt-target: foo.rs\nfn main() {}\n";
+        let file_config = config.with_file_directives(src).unwrap();
+        assert_eq!(file_config.max_width(), 80);
+        assert_eq!(config.max_width(), 90);
+
+        let src = "// rustfmt-no_such_option: true\n";
+        assert!(config.with_file_directives(src).is_err());
+        let src = "// rustfmt-max_width: wide\n";
+        assert!(config.with_file_directives(src).is_err());
+    }
+
+    #[test]
+    fn test_extends() {
+        let path = Path::new("tests/config/extends/extending.toml");
+        let config = Config::from_toml_path(path).unwrap();
+
+        assert_eq!(config.max_width(), 80);
+        assert_eq!(config.tab_spaces(), 2);
+        assert_eq!(config.was_set().tab_spaces(), true);
+    }
+
+    #[test]
+    fn test_extends_cycle() {
+        let path = Path::new("tests/config/extends/cyclic.toml");
+        assert!(Config::from_toml_path(path).is_err());
+    }
+
     #[test]
     fn test_print_docs_exclude_unstable() {
         use self::mock::Config;
@@ -544,13 +1036,65 @@ mod test {
         assert!(config.license_template.is_none());
     }
 
+    #[test]
+    fn test_license_text() {
+        if !crate::is_nightly_channel!() {
+            return;
+        }
+        let toml = r#"license_template_path = "tests/license-template/insert.txt""#;
+        let config = Config::from_toml(toml, Path::new("")).unwrap();
+        assert_eq!(
+            config.license_text(),
+            Some("// Licensed under the MIT license.\n\n")
+        );
+
+        let toml = r#"license_template_path = "tests/license-template/lt.txt""#;
+        let config = Config::from_toml(toml, Path::new("")).unwrap();
+        assert!(config.license_text().is_none());
+    }
+
+    #[test]
+    fn test_style_edition_defaults() {
+        if !crate::is_nightly_channel!() {
+            return;
+        }
+        let config = Config::from_toml(r#"style_edition = "2021""#, Path::new("")).unwrap();
+        assert_eq!(config.version(), Version::Two);
+        assert!(!config.was_set().version());
+
+        let toml = "style_edition = \"2021\"\nversion = \"One\"";
+        let config = Config::from_toml(toml, Path::new("")).unwrap();
+        assert_eq!(config.version(), Version::One);
+
+        let mut config = Config::default();
+        config.override_value("style_edition", "2021");
+        assert_eq!(config.version(), Version::Two);
+        config.override_value("style_edition", "2015");
+        assert_eq!(config.version(), Version::One);
+    }
+
+    #[test]
+    fn test_style_edition_defaults_are_valid() {
+        for &style_edition in &[
+            StyleEdition::Edition2015,
+            StyleEdition::Edition2018,
+            StyleEdition::Edition2021,
+        ] {
+            for &(key, val) in style_edition.defaults() {
+                assert!(Config::is_valid_key_val(key, val), "{} = {}", key, val);
+            }
+        }
+    }
+
     #[test]
     fn test_dump_default_config() {
         let default_config = format!(
             r#"max_width = 100
 hard_tabs = false
 tab_spaces = 4
+continuation_indent_width = 0
 newline_style = "Auto"
+newline_at_eof = true
 indent_style = "Block"
 use_small_heuristics = "Default"
 fn_call_width = 60
@@ -560,23 +1104,39 @@ struct_variant_width = 35
 array_width = 60
 chain_width = 60
 single_line_if_else_max_width = 50
+short_array_element_width_threshold = 10
 wrap_comments = false
 format_code_in_doc_comments = false
+doc_comment_code_block_width = 100
 comment_width = 80
 normalize_comments = false
 normalize_doc_attributes = false
+preserve_block_comment_indent = false
+preserve_inner_doc_comments = false
+trim_comment_trailing_whitespace = true
 license_template_path = ""
+insert_license = false
 format_strings = false
+string_lit_width = 0
+string_wrap_indent = "Visual"
+normalize_numeric_literals = false
+hex_literal_case = "Preserve"
 format_macro_matchers = false
 format_macro_bodies = true
 empty_item_single_line = true
+fn_empty_single_line = true
+impl_empty_single_line = true
 struct_lit_single_line = true
+struct_lit_single_line_width = 0
 fn_single_line = false
 where_single_line = false
+where_bound_layout = "Front"
+trait_bound_layout = "Front"
 imports_indent = "Block"
 imports_layout = "Mixed"
 imports_granularity = "Preserve"
 group_imports = "Preserve"
+normalize_imports_self = false
 reorder_imports = true
 reorder_modules = true
 reorder_impl_items = false
@@ -584,6 +1144,8 @@ type_punctuation_density = "Wide"
 space_before_colon = false
 space_after_colon = true
 spaces_around_ranges = false
+space_after_macro_bang = false
+space_around_attr_eq = true
 binop_separator = "Front"
 remove_nested_parens = true
 combine_control_expr = true
@@ -592,36 +1154,52 @@ struct_field_align_threshold = 0
 enum_discrim_align_threshold = 0
 match_arm_blocks = true
 match_arm_leading_pipes = "Never"
+match_pattern_separator_break_point = "Before"
+blank_lines_between_match_arms = 1
 force_multiline_blocks = false
-fn_args_layout = "Tall"
+closure_body_braces = false
+fn_params_layout = "Tall"
+fn_generics_space = "None"
+space_before_fn_paren = false
 brace_style = "SameLineWhere"
 control_brace_style = "AlwaysSameLine"
 trailing_semicolon = true
+strip_tail_return = false
 trailing_comma = "Vertical"
 match_block_trailing_comma = false
 blank_lines_upper_bound = 1
 blank_lines_lower_bound = 0
 edition = "2015"
 version = "One"
+style_edition = "2015"
 inline_attribute_width = 0
 merge_derives = true
+derive_trailing_comma = true
 use_try_shorthand = false
 use_field_init_shorthand = false
 force_explicit_abi = true
 condense_wildcard_suffixes = false
 color = "Auto"
+verbose_diff = false
+diff_context_lines = 3
 required_version = "{}"
 unstable_features = false
 disable_all_formatting = false
+format_generated_files = true
 skip_children = false
 hide_parse_errors = false
+error_emission = "Human"
 error_on_line_overflow = false
+error_on_line_overflow_comments = true
 error_on_unformatted = false
 report_todo = "Never"
 report_fixme = "Never"
+report_unsafe_without_safety_comment = "Never"
+report_unreachable_match_arms = "Never"
 ignore = []
 emit_mode = "Files"
 make_backup = false
+backup_suffix = "bk"
 "#,
             env!("CARGO_PKG_VERSION")
         );
@@ -723,6 +1301,130 @@ make_backup = false
         }
     }
 
+    #[test]
+    fn test_check_toml() {
+        let check = |toml: &str| Config::check_toml(toml, Path::new(""));
+        assert!(check("max_width = 80\nfn_args_layout = \"Vertical\"").is_empty());
+        assert_eq!(
+            check("max_widht = 80"),
+            vec!["unknown configuration option `max_widht`"]
+        );
+        assert_eq!(check("max_width = \"80\"").len(), 1);
+        assert_eq!(check("fn_params_layout = \"Wide\"").len(), 1);
+        assert_eq!(
+            check("max_width = 80\nchain_width = 90"),
+            vec!["`chain_width` (90) cannot exceed `max_width` (80)"]
+        );
+        assert_eq!(
+            check("required_version = \"0.0.0\"").len(),
+            1,
+            "a mismatching required_version is a problem"
+        );
+    }
+
+    #[test]
+    fn test_valid_backup_suffix() {
+        assert!(is_valid_backup_suffix("bk"));
+        assert!(is_valid_backup_suffix(".orig"));
+        assert!(!is_valid_backup_suffix(""));
+        assert!(!is_valid_backup_suffix("."));
+        assert!(!is_valid_backup_suffix("bk/orig"));
+        assert!(!is_valid_backup_suffix("rs"));
+        assert!(!is_valid_backup_suffix(".rs"));
+        assert!(!is_valid_backup_suffix("tmp"));
+        assert!(!is_valid_backup_suffix(".tmp"));
+    }
+
+    #[test]
+    fn test_cached_toml_path() {
+        let small_tabs = Path::new("tests/config/small_tabs.toml");
+        let skip_children = Path::new("tests/config/skip_children.toml");
+        for _ in 0..2 {
+            let config = Config::from_cached_toml_path(small_tabs).unwrap();
+            assert_eq!(config.tab_spaces(), 2);
+            assert!(!config.skip_children());
+            let config = Config::from_cached_toml_path(skip_children).unwrap();
+            assert_eq!(config.tab_spaces(), 4);
+            assert!(config.skip_children());
+        }
+    }
+
+    #[test]
+    fn test_cached_toml_path_with_modified_extends() {
+        let dir = env::temp_dir().join("rustfmt-test-cached-toml-path-extends");
+        fs::create_dir_all(&dir).unwrap();
+        let base = dir.join("base.toml");
+        let extending = dir.join("rustfmt.toml");
+        fs::write(&base, "tab_spaces = 2\n").unwrap();
+        fs::write(&extending, "extends = \"base.toml\"\n").unwrap();
+        let config = Config::from_cached_toml_path(&extending).unwrap();
+        assert_eq!(config.tab_spaces(), 2);
+
+        // Rewrite the extended file until its modification time changes.
+        let loaded = fs::metadata(&base).unwrap().modified().unwrap();
+        while fs::metadata(&base).unwrap().modified().unwrap() == loaded {
+            std::thread::sleep(std::time::Duration::from_millis(10));
+            fs::write(&base, "tab_spaces = 8\n").unwrap();
+        }
+        let config = Config::from_cached_toml_path(&extending).unwrap();
+        assert_eq!(config.tab_spaces(), 8);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[cfg(test)]
+    mod renamed_options {
+        use super::*;
+
+        #[test]
+        fn test_old_name_in_toml() {
+            let toml = r#"fn_args_layout = "Vertical""#;
+            let config = Config::from_toml(toml, Path::new("")).unwrap();
+            assert_eq!(config.fn_params_layout(), Density::Vertical);
+            assert!(config.was_set().fn_params_layout());
+        }
+
+        #[test]
+        fn test_new_name_takes_precedence() {
+            let toml = r#"
+                fn_args_layout = "Vertical"
+                fn_params_layout = "Compressed"
+            "#;
+            let config = Config::from_toml(toml, Path::new("")).unwrap();
+            assert_eq!(config.fn_params_layout(), Density::Compressed);
+        }
+
+        #[test]
+        fn test_old_name_overridden() {
+            assert!(Config::is_valid_name("fn_args_layout"));
+            assert!(Config::is_valid_key_val("fn_args_layout", "Compressed"));
+            assert!(!Config::is_valid_key_val("fn_args_layout", "Wide"));
+            let mut config = Config::default();
+            config.override_value("fn_args_layout", "Compressed");
+            assert_eq!(config.fn_params_layout(), Density::Compressed);
+        }
+
+        #[test]
+        #[allow(deprecated)]
+        fn test_old_name_accessors() {
+            let mut config = Config::default();
+            config.set().fn_args_layout(Density::Vertical);
+            assert_eq!(config.fn_params_layout(), Density::Vertical);
+            assert_eq!(config.fn_args_layout(), Density::Vertical);
+        }
+
+        #[test]
+        fn test_british_spellings() {
+            let toml = r#"
+                normalise_comments = true
+                normalise_doc_attributes = true
+            "#;
+            let config = Config::from_toml(toml, Path::new("")).unwrap();
+            assert!(config.normalize_comments());
+            assert!(config.normalize_doc_attributes());
+            assert_eq!(config.diff_from_default().len(), 2);
+        }
+    }
+
     #[cfg(test)]
     mod use_small_heuristics {
         use super::*;
@@ -69,6 +69,35 @@ pub enum Density {
     Vertical,
 }
 
+#[config_type]
+/// Spacing around the generic parameters of a function signature.
+pub enum FnGenericsSpace {
+    /// No spaces: `fn lorem<T>(ipsum: T)`
+    None,
+    /// A space before the generic parameters: `fn lorem <T>(ipsum: T)`
+    OnlyBefore,
+    /// A space after the generic parameters: `fn lorem<T> (ipsum: T)`
+    OnlyAfter,
+    /// Spaces before and after the generic parameters: `fn lorem <T> (ipsum: T)`
+    Both,
+}
+
+impl FnGenericsSpace {
+    pub(crate) fn space_before(self) -> bool {
+        match self {
+            FnGenericsSpace::OnlyBefore | FnGenericsSpace::Both => true,
+            FnGenericsSpace::None | FnGenericsSpace::OnlyAfter => false,
+        }
+    }
+
+    pub(crate) fn space_after(self) -> bool {
+        match self {
+            FnGenericsSpace::OnlyAfter | FnGenericsSpace::Both => true,
+            FnGenericsSpace::None | FnGenericsSpace::OnlyBefore => false,
+        }
+    }
+}
+
 #[config_type]
 /// Spacing around type combinators.
 pub enum TypeDensity {
@@ -156,6 +185,9 @@ pub enum EmitMode {
     /// This option is designed to be run in CI where a non-zero exit signifies
     /// non-standard code formatting. Used for `--check`.
     Diff,
+    /// Prints the names of the files which would be reformatted, without writing to them, and
+    /// quits with exit code 1 if there are any.
+    FilesWithDiff,
 }
 
 /// Client-preference for coloured output.
@@ -376,6 +408,10 @@ impl IgnoreList {
     pub fn rustfmt_toml_path(&self) -> &Path {
         &self.rustfmt_toml_path
     }
+
+    pub(crate) fn path_set(&self) -> &HashSet<PathBuf> {
+        &self.path_set
+    }
 }
 
 impl FromStr for IgnoreList {
@@ -442,3 +478,90 @@ pub enum MatchArmLeadingPipe {
     /// Preserve any existing leading pipes
     Preserve,
 }
+
+/// Where to break a long or-pattern relative to its `|` separators.
+#[config_type]
+pub enum MatchPatternBreak {
+    /// Put the `|` at the start of the continuation line
+    Before,
+    /// Put the `|` at the end of the broken line
+    After,
+}
+
+/// Controls the case of the digits of hex literals.
+#[config_type]
+pub enum HexLiteralCase {
+    /// Leave the digits as written
+    Preserve,
+    /// Use uppercase digits
+    Upper,
+    /// Use lowercase digits
+    Lower,
+}
+
+/// How errors from the parser are reported.
+#[config_type]
+pub enum ErrorEmission {
+    /// Human readable messages, like those of rustc
+    Human,
+    /// One JSON object per message, in the format of rustc's `--error-format=json`
+    Json,
+}
+
+/// How the `+`-separated bounds of a single where-clause predicate are laid out.
+#[config_type]
+pub enum WhereBoundLayout {
+    /// Put the bounds on one line if they fit, otherwise one per line with a leading `+`
+    Front,
+    /// Put the bounds on one line if they fit, otherwise one per line with a trailing `+`
+    Back,
+    /// Always put each bound on its own line with a leading `+`
+    VerticalFront,
+    /// Always put each bound on its own line with a trailing `+`
+    VerticalBack,
+}
+
+impl WhereBoundLayout {
+    pub(crate) fn is_vertical(self) -> bool {
+        match self {
+            WhereBoundLayout::VerticalFront | WhereBoundLayout::VerticalBack => true,
+            WhereBoundLayout::Front | WhereBoundLayout::Back => false,
+        }
+    }
+
+    pub(crate) fn separator_place(self) -> SeparatorPlace {
+        match self {
+            WhereBoundLayout::Front | WhereBoundLayout::VerticalFront => SeparatorPlace::Front,
+            WhereBoundLayout::Back | WhereBoundLayout::VerticalBack => SeparatorPlace::Back,
+        }
+    }
+}
+
+/// The edition whose frozen set of defaults is used for the options which aren't
+/// set explicitly.
+#[config_type]
+pub enum StyleEdition {
+    #[value = "2015"]
+    #[doc_hint = "2015"]
+    /// Style edition 2015.
+    Edition2015,
+    #[value = "2018"]
+    #[doc_hint = "2018"]
+    /// Style edition 2018.
+    Edition2018,
+    #[value = "2021"]
+    #[doc_hint = "2021"]
+    /// Style edition 2021.
+    Edition2021,
+}
+
+impl StyleEdition {
+    /// The defaults of this style edition, as `(option, value)` pairs. Every style edition
+    /// lists the same options, so that switching between them doesn't leave stale values.
+    pub(crate) fn defaults(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            StyleEdition::Edition2015 | StyleEdition::Edition2018 => &[("version", "One")],
+            StyleEdition::Edition2021 => &[("version", "Two")],
+        }
+    }
+}
@@ -2,6 +2,7 @@ pub(crate) use self::checkstyle::*;
 pub(crate) use self::diff::*;
 pub(crate) use self::files::*;
 pub(crate) use self::files_with_backup::*;
+pub(crate) use self::files_with_diff::*;
 pub(crate) use self::json::*;
 pub(crate) use self::modified_lines::*;
 pub(crate) use self::stdout::*;
@@ -13,6 +14,7 @@ mod checkstyle;
 mod diff;
 mod files;
 mod files_with_backup;
+mod files_with_diff;
 mod json;
 mod modified_lines;
 mod stdout;
@@ -1,6 +1,7 @@
 use self::xml::XmlEscaped;
 use super::*;
 use crate::rustfmt_diff::{make_diff, DiffLine, Mismatch};
+use std::collections::HashSet;
 use std::io::{self, Write};
 use std::path::Path;
 
@@ -46,28 +47,121 @@ where
     T: Write,
 {
     write!(writer, r#"<file name="{}">"#, filename.display())?;
+    let removed = removed_lines(&diff);
     for mismatch in diff {
-        let begin_line = mismatch.line_number;
-        let mut current_line;
-        let mut line_counter = 0;
-        for line in mismatch.lines {
-            // Do nothing with `DiffLine::Context` and `DiffLine::Resulting`.
-            if let DiffLine::Expected(message) = line {
-                current_line = begin_line + line_counter;
-                line_counter += 1;
-                write!(
-                    writer,
-                    r#"<error line="{}" severity="warning" message="Should be `{}`" />"#,
-                    current_line,
-                    XmlEscaped(&message)
-                )?;
-            }
+        let category = Category::of(&mismatch.lines, &removed);
+        let original: Vec<&str> = mismatch
+            .lines
+            .iter()
+            .filter_map(|line| match line {
+                DiffLine::Resulting(s) => Some(s.as_str()),
+                _ => None,
+            })
+            .collect();
+        let expected = mismatch.lines.iter().filter_map(|line| match line {
+            DiffLine::Expected(s) => Some(s.as_str()),
+            _ => None,
+        });
+        for (i, message) in expected.enumerate() {
+            // Report the position in the original source: prefer the original line with the
+            // same non-whitespace content, otherwise fall back to the line at the same offset
+            // (or the last replaced line for inserted lines).
+            let stripped = strip_whitespace(message);
+            let idx = original
+                .iter()
+                .position(|orig| !stripped.is_empty() && strip_whitespace(orig) == stripped)
+                .unwrap_or_else(|| i.min(original.len().saturating_sub(1)));
+            let line = mismatch.line_number_orig + idx as u32;
+            let column = original
+                .get(idx)
+                .map_or(1, |orig| first_difference_column(orig, message));
+            write!(
+                writer,
+                r#"<error line="{}" column="{}" severity="warning" "#,
+                line, column,
+            )?;
+            write!(
+                writer,
+                r#"message="Should be `{}`" source="rustfmt.{}" />"#,
+                XmlEscaped(message),
+                category.name(),
+            )?;
         }
     }
     write!(writer, "</file>")?;
     Ok(())
 }
 
+/// The kind of change rustfmt wants to make to a chunk of lines, reported as the checkstyle
+/// `source` of each error.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Category {
+    /// Only whitespace (including blank lines) differs.
+    Whitespace,
+    /// The same code is split across lines differently.
+    Wrap,
+    /// The lines are moved from elsewhere in the file.
+    Reorder,
+    /// Anything else.
+    Other,
+}
+
+impl Category {
+    fn of(lines: &[DiffLine], removed: &HashSet<String>) -> Category {
+        let mut original = vec![];
+        let mut expected = vec![];
+        for line in lines {
+            match line {
+                DiffLine::Resulting(s) => original.push(strip_whitespace(s)),
+                DiffLine::Expected(s) => expected.push(strip_whitespace(s)),
+                DiffLine::Context(_) => {}
+            }
+        }
+        original.retain(|s| !s.is_empty());
+        expected.retain(|s| !s.is_empty());
+
+        if original == expected {
+            Category::Whitespace
+        } else if original.concat() == expected.concat() {
+            Category::Wrap
+        } else if !expected.is_empty() && expected.iter().all(|s| removed.contains(s)) {
+            Category::Reorder
+        } else {
+            Category::Other
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Category::Whitespace => "whitespace",
+            Category::Wrap => "wrap",
+            Category::Reorder => "reorder",
+            Category::Other => "other",
+        }
+    }
+}
+
+/// Collects every line removed anywhere in the file, with whitespace stripped.
+fn removed_lines(diff: &[Mismatch]) -> HashSet<String> {
+    diff.iter()
+        .flat_map(|mismatch| mismatch.lines.iter())
+        .filter_map(|line| match line {
+            DiffLine::Resulting(s) => Some(strip_whitespace(s)),
+            _ => None,
+        })
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+fn strip_whitespace(s: &str) -> String {
+    s.chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+/// Returns the 1-based column of the first character at which `a` and `b` differ.
+fn first_difference_column(a: &str, b: &str) -> usize {
+    a.chars().zip(b.chars()).take_while(|(x, y)| x == y).count() + 1
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -120,7 +214,10 @@ mod tests {
         let exp_bin_xml = vec![
             format!(r#"<file name="{}">"#, bin_file),
             format!(
-                r#"<error line="2" severity="warning" message="Should be `{}`" />"#,
+                concat!(
+                    r#"<error line="2" column="1" severity="warning" "#,
+                    r#"message="Should be `{}`" source="rustfmt.whitespace" />"#,
+                ),
                 XmlEscaped(&r#"    println!("Hello, world!");"#),
             ),
             String::from("</file>"),
@@ -128,7 +225,10 @@ mod tests {
         let exp_lib_xml = vec![
             format!(r#"<file name="{}">"#, lib_file),
             format!(
-                r#"<error line="2" severity="warning" message="Should be `{}`" />"#,
+                concat!(
+                    r#"<error line="2" column="1" severity="warning" "#,
+                    r#"message="Should be `{}`" source="rustfmt.whitespace" />"#,
+                ),
                 XmlEscaped(&r#"    println!("Greetings!");"#),
             ),
             String::from("</file>"),
@@ -145,4 +245,56 @@ mod tests {
             .join(""),
         );
     }
+
+    // Categorizes the last mismatch between `original` and `formatted`.
+    fn categorize(original: &[&str], formatted: &[&str]) -> Category {
+        let diff = make_diff(&original.join("\n"), &formatted.join("\n"), 0);
+        let removed = removed_lines(&diff);
+        Category::of(&diff.last().unwrap().lines, &removed)
+    }
+
+    #[test]
+    fn categorizes_mismatches() {
+        assert_eq!(
+            categorize(&["{", "x", "}"], &["{", "    x", "}"]),
+            Category::Whitespace
+        );
+        assert_eq!(
+            categorize(&["a", "", "", "b"], &["a", "", "b"]),
+            Category::Whitespace
+        );
+        assert_eq!(
+            categorize(&["foo(a,", "b);"], &["foo(a, b);"]),
+            Category::Wrap
+        );
+        assert_eq!(
+            categorize(
+                &["use c;", "use a;", "use b;"],
+                &["use a;", "use b;", "use c;"],
+            ),
+            Category::Reorder
+        );
+        assert_eq!(
+            categorize(&["let x = (1);"], &["let x = 1;"]),
+            Category::Other
+        );
+    }
+
+    #[test]
+    fn reports_original_line_and_column() {
+        let original = "fn main() {\n\n\n    let x  = 1;\n}\n";
+        let formatted = "fn main() {\n    let x = 1;\n}\n";
+        let mut writer = Vec::new();
+        let diff = make_diff(original, formatted, 0);
+        let _ = output_checkstyle_file(&mut writer, &PathBuf::from("a.rs"), diff);
+        assert_eq!(
+            String::from_utf8(writer).unwrap(),
+            concat!(
+                r#"<file name="a.rs">"#,
+                r#"<error line="4" column="11" severity="warning" "#,
+                r#"message="Should be `    let x = 1;`" source="rustfmt.whitespace" />"#,
+                "</file>",
+            ),
+        );
+    }
 }
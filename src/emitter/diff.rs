@@ -1,6 +1,6 @@
 use super::*;
 use crate::config::Config;
-use crate::rustfmt_diff::{make_diff, print_diff};
+use crate::rustfmt_diff::{make_diff, print_diff, print_unified_diff};
 
 pub(crate) struct DiffEmitter {
     config: Config,
@@ -22,20 +22,19 @@ impl Emitter for DiffEmitter {
             formatted_text,
         }: FormattedFile<'_>,
     ) -> Result<EmitterResult, io::Error> {
-        const CONTEXT_SIZE: usize = 3;
-        let mismatch = make_diff(&original_text, formatted_text, CONTEXT_SIZE);
+        let context_size = self.config.diff_context_lines();
+        let mismatch = make_diff(&original_text, formatted_text, context_size);
         let has_diff = !mismatch.is_empty();
 
-        if has_diff {
-            if self.config.print_misformatted_file_names() {
-                writeln!(output, "{}", ensure_real_path(filename).display())?;
-            } else {
-                print_diff(
-                    mismatch,
-                    |line_num| format!("Diff in {} at line {}:", filename, line_num),
-                    &self.config,
-                );
-            }
+        if has_diff && self.config.verbose_diff() {
+            print_unified_diff(output, mismatch, &filename.to_string(), &self.config)?;
+        } else if has_diff {
+            print_diff(
+                output,
+                mismatch,
+                |line_num| format!("Diff in {} at line {}:", filename, line_num),
+                &self.config,
+            )?;
         } else if original_text != formatted_text {
             // This occurs when the only difference between the original and formatted values
             // is the newline style. This happens because The make_diff function compares the
@@ -75,46 +74,6 @@ mod tests {
         assert_eq!(writer.len(), 0);
     }
 
-    #[test]
-    fn prints_file_names_when_config_is_enabled() {
-        let bin_file = "src/bin.rs";
-        let bin_original = "fn main() {\nprintln!(\"Hello, world!\");\n}";
-        let bin_formatted = "fn main() {\n    println!(\"Hello, world!\");\n}";
-        let lib_file = "src/lib.rs";
-        let lib_original = "fn greet() {\nprintln!(\"Greetings!\");\n}";
-        let lib_formatted = "fn greet() {\n    println!(\"Greetings!\");\n}";
-
-        let mut writer = Vec::new();
-        let mut config = Config::default();
-        config.set().print_misformatted_file_names(true);
-        let mut emitter = DiffEmitter::new(config);
-        let _ = emitter
-            .emit_formatted_file(
-                &mut writer,
-                FormattedFile {
-                    filename: &FileName::Real(PathBuf::from(bin_file)),
-                    original_text: bin_original,
-                    formatted_text: bin_formatted,
-                },
-            )
-            .unwrap();
-        let _ = emitter
-            .emit_formatted_file(
-                &mut writer,
-                FormattedFile {
-                    filename: &FileName::Real(PathBuf::from(lib_file)),
-                    original_text: lib_original,
-                    formatted_text: lib_formatted,
-                },
-            )
-            .unwrap();
-
-        assert_eq!(
-            String::from_utf8(writer).unwrap(),
-            format!("{}\n{}\n", bin_file, lib_file),
-        )
-    }
-
     #[test]
     fn prints_newline_message_with_only_newline_style_diff() {
         let mut writer = Vec::new();
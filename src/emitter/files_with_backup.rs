@@ -1,8 +1,17 @@
 use super::*;
+use crate::config::is_valid_backup_suffix;
 use std::fs;
 
-#[derive(Debug, Default)]
-pub(crate) struct FilesWithBackupEmitter;
+#[derive(Debug)]
+pub(crate) struct FilesWithBackupEmitter {
+    backup_suffix: String,
+}
+
+impl FilesWithBackupEmitter {
+    pub(crate) fn new(backup_suffix: String) -> Self {
+        Self { backup_suffix }
+    }
+}
 
 impl Emitter for FilesWithBackupEmitter {
     fn emit_formatted_file(
@@ -16,11 +25,17 @@ impl Emitter for FilesWithBackupEmitter {
     ) -> Result<EmitterResult, io::Error> {
         let filename = ensure_real_path(filename);
         if original_text != formatted_text {
+            if !is_valid_backup_suffix(&self.backup_suffix) {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("invalid backup suffix `{}`", self.backup_suffix),
+                ));
+            }
             // Do a little dance to make writing safer - write to a temp file
-            // rename the original to a .bk, then rename the temp file to the
+            // rename the original to a backup, then rename the temp file to the
             // original.
             let tmp_name = filename.with_extension("tmp");
-            let bk_name = filename.with_extension("bk");
+            let bk_name = filename.with_extension(self.backup_suffix.trim_start_matches('.'));
 
             fs::write(&tmp_name, formatted_text)?;
             fs::rename(filename, bk_name)?;
@@ -0,0 +1,129 @@
+use super::*;
+
+/// Prints the names of the files which would be reformatted, without writing to them.
+#[derive(Debug, Default)]
+pub(crate) struct FilesWithDiffEmitter {
+    null_separated: bool,
+}
+
+impl FilesWithDiffEmitter {
+    pub(crate) fn new(null_separated: bool) -> Self {
+        Self { null_separated }
+    }
+}
+
+impl Emitter for FilesWithDiffEmitter {
+    fn emit_formatted_file(
+        &mut self,
+        output: &mut dyn Write,
+        FormattedFile {
+            filename,
+            original_text,
+            formatted_text,
+        }: FormattedFile<'_>,
+    ) -> Result<EmitterResult, io::Error> {
+        let has_diff = original_text != formatted_text;
+        if has_diff {
+            let separator = if self.null_separated { '\0' } else { '\n' };
+            write!(
+                output,
+                "{}{}",
+                ensure_real_path(filename).display(),
+                separator
+            )?;
+        }
+        Ok(EmitterResult { has_diff })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{Config, EmitMode};
+    use std::path::PathBuf;
+
+    fn emit(emitter: &mut dyn Emitter, files: &[(&str, &str, &str)]) -> (String, bool) {
+        let mut writer = Vec::new();
+        let mut has_diff = false;
+        for (filename, original_text, formatted_text) in files {
+            let result = emitter
+                .emit_formatted_file(
+                    &mut writer,
+                    FormattedFile {
+                        filename: &FileName::Real(PathBuf::from(filename)),
+                        original_text,
+                        formatted_text,
+                    },
+                )
+                .unwrap();
+            has_diff |= result.has_diff;
+        }
+        (String::from_utf8(writer).unwrap(), has_diff)
+    }
+
+    #[test]
+    fn does_not_print_when_no_files_reformatted() {
+        let files = [("src/lib.rs", "fn empty() {}\n", "fn empty() {}\n")];
+        let (output, has_diff) = emit(&mut FilesWithDiffEmitter::default(), &files);
+        assert_eq!(output, "");
+        assert!(!has_diff);
+    }
+
+    #[test]
+    fn prints_names_of_reformatted_files() {
+        let files = [
+            ("src/bin.rs", "fn main() {\n}\n", "fn main() {}\n"),
+            ("src/lib.rs", "fn empty() {}\n", "fn empty() {}\n"),
+            ("src/foo.rs", "fn foo() {}\n", "fn foo() {}\r\n"),
+        ];
+
+        let mut emitter = FilesWithDiffEmitter::new(false);
+        let (output, has_diff) = emit(&mut emitter, &files);
+        assert_eq!(output, "src/bin.rs\nsrc/foo.rs\n");
+        assert!(has_diff);
+
+        let mut emitter = FilesWithDiffEmitter::new(true);
+        let (output, has_diff) = emit(&mut emitter, &files);
+        assert_eq!(output, "src/bin.rs\0src/foo.rs\0");
+        assert!(has_diff);
+    }
+
+    #[test]
+    fn is_used_for_check_with_file_names() {
+        let bin_file = "src/bin.rs";
+        let bin_original = "fn main() {\nprintln!(\"Hello, world!\");\n}";
+        let bin_formatted = "fn main() {\n    println!(\"Hello, world!\");\n}";
+        let lib_file = "src/lib.rs";
+        let lib_original = "fn greet() {\nprintln!(\"Greetings!\");\n}";
+        let lib_formatted = "fn greet() {\n    println!(\"Greetings!\");\n}";
+
+        let mut config = Config::default();
+        config.set().emit_mode(EmitMode::Diff);
+        config.set().print_misformatted_file_names(true);
+        let mut emitter = crate::create_emitter(&config);
+        let (output, has_diff) = emit(
+            &mut *emitter,
+            &[
+                (bin_file, bin_original, bin_formatted),
+                (lib_file, lib_original, lib_formatted),
+            ],
+        );
+        assert_eq!(output, format!("{}\n{}\n", bin_file, lib_file));
+        assert!(has_diff);
+    }
+
+    #[test]
+    fn is_used_for_files_with_diff_emit_mode() {
+        let mut config = Config::default();
+        config.set().emit_mode(EmitMode::FilesWithDiff);
+        config.set().null_separated_file_names(true);
+        let mut emitter = crate::create_emitter(&config);
+        let files = [
+            ("src/bin.rs", "fn main() {\n}\n", "fn main() {}\n"),
+            ("src/lib.rs", "fn empty() {}\n", "fn empty() {}\n"),
+        ];
+        let (output, has_diff) = emit(&mut *emitter, &files);
+        assert_eq!(output, "src/bin.rs\0");
+        assert!(has_diff);
+    }
+}
@@ -13,7 +13,7 @@ use crate::comment::{
     rewrite_missing_comment, CharClasses, FindUncommented,
 };
 use crate::config::lists::*;
-use crate::config::{Config, ControlBraceStyle, IndentStyle, Version};
+use crate::config::{Config, ControlBraceStyle, HexLiteralCase, IndentStyle, Version};
 use crate::lists::{
     definitive_tactic, itemize_list, shape_for_tactic, struct_lit_formatting, struct_lit_shape,
     struct_lit_tactic, write_list, ListFormatting, Separator,
@@ -1176,6 +1176,17 @@ pub(crate) fn rewrite_literal(
 ) -> Option<String> {
     match l.kind {
         ast::LitKind::Str(_, ast::StrStyle::Cooked) => rewrite_string_lit(context, l.span, shape),
+        ast::LitKind::Int(..) | ast::LitKind::Float(..) => {
+            let mut lit = Cow::from(context.snippet(l.span));
+            if context.config.normalize_numeric_literals() {
+                lit = Cow::from(normalize_numeric_literal(&lit));
+            }
+            match context.config.hex_literal_case() {
+                HexLiteralCase::Preserve => (),
+                case => lit = Cow::from(format_hex_literal_case(&lit, case)),
+            }
+            wrap_str(lit.into_owned(), context.config.max_width(), shape)
+        }
         _ => wrap_str(
             context.snippet(l.span).to_owned(),
             context.config.max_width(),
@@ -1184,10 +1195,60 @@ pub(crate) fn rewrite_literal(
     }
 }
 
+/// Splits a numeric literal into its radix prefix, digits (including any fraction
+/// and exponent) and type suffix.
+fn split_numeric_literal(lit: &str) -> (&str, &str, &str) {
+    let (prefix, rest) = match lit.get(..2) {
+        Some(prefix @ "0x") | Some(prefix @ "0X") | Some(prefix @ "0o") | Some(prefix @ "0O")
+        | Some(prefix @ "0b") | Some(prefix @ "0B") => (prefix, &lit[2..]),
+        _ => ("", lit),
+    };
+    let is_hex = prefix.eq_ignore_ascii_case("0x");
+    // In hex literals `e` and `f` are digits, so only `i` and `u` can start the suffix.
+    let suffix_start = rest
+        .find(|c: char| {
+            if is_hex {
+                c == 'i' || c == 'u'
+            } else {
+                c.is_ascii_alphabetic() && c != 'e' && c != 'E'
+            }
+        })
+        .unwrap_or(rest.len());
+    let (digits, suffix) = rest.split_at(suffix_start);
+    (prefix, digits, suffix)
+}
+
+/// Lowercases the radix prefix and hex digits of an integer literal, and the exponent marker of a
+/// float literal. Digit-group underscores and the type suffix are kept as written.
+fn normalize_numeric_literal(lit: &str) -> String {
+    let (prefix, digits, suffix) = split_numeric_literal(lit);
+    format!(
+        "{}{}{}",
+        prefix.to_ascii_lowercase(),
+        digits.to_ascii_lowercase(),
+        suffix
+    )
+}
+
+/// Changes the case of the digits of a hex literal. Other literals, the `0x`
+/// prefix and the type suffix are left as written.
+fn format_hex_literal_case(lit: &str, case: HexLiteralCase) -> String {
+    let (prefix, digits, suffix) = split_numeric_literal(lit);
+    if !prefix.eq_ignore_ascii_case("0x") {
+        return lit.to_owned();
+    }
+    let digits = match case {
+        HexLiteralCase::Preserve => digits.to_owned(),
+        HexLiteralCase::Upper => digits.to_ascii_uppercase(),
+        HexLiteralCase::Lower => digits.to_ascii_lowercase(),
+    };
+    format!("{}{}{}", prefix, digits, suffix)
+}
+
 fn rewrite_string_lit(context: &RewriteContext<'_>, span: Span, shape: Shape) -> Option<String> {
     let string_lit = context.snippet(span);
 
-    if !context.config.format_strings() {
+    if !context.config.format_strings() || is_string_lit_kept(context, string_lit, shape) {
         if string_lit
             .lines()
             .dropping_back(1)
@@ -1203,11 +1264,51 @@ fn rewrite_string_lit(context: &RewriteContext<'_>, span: Span, shape: Shape) ->
     // Remove the quote characters.
     let str_lit = &string_lit[1..string_lit.len() - 1];
 
-    rewrite_string(
-        str_lit,
-        &StringFormat::new(shape.visual_indent(0), context.config),
-        shape.width.saturating_sub(2),
-    )
+    match context.config.string_wrap_indent() {
+        IndentStyle::Visual => rewrite_string(
+            str_lit,
+            &StringFormat::new(shape.visual_indent(0), context.config),
+            shape.width.saturating_sub(2),
+        ),
+        IndentStyle::Block => {
+            // Continuation lines start at the next block indent, but must still end
+            // where the first line does so that any trailing characters fit.
+            let indent = shape.indent.block_only().block_indent(context.config);
+            let fmt = StringFormat {
+                line_start: "",
+                ..StringFormat::new(Shape { indent, ..shape }, context.config)
+            };
+            let newline_max_chars =
+                (shape.used_width() + shape.width).saturating_sub(indent.width() + 1);
+            rewrite_string(str_lit, &fmt, newline_max_chars)
+        }
+    }
+}
+
+// Whether `format_strings` should leave a single line string literal as it is, either because
+// its layout is meaningful or because it is narrower than `string_lit_width`.
+fn is_string_lit_kept(context: &RewriteContext<'_>, string_lit: &str, shape: Shape) -> bool {
+    if string_lit.contains('\n') {
+        return false;
+    }
+    if has_newline_escape(string_lit) || string_lit.contains("  ") {
+        return true;
+    }
+
+    let threshold = context.config.string_lit_width();
+    threshold > 0 && shape.used_width() + unicode_str_width(string_lit) < threshold
+}
+
+// Whether the given string literal contains a `\n` escape, as opposed to an escaped backslash
+// followed by `n`.
+fn has_newline_escape(string_lit: &str) -> bool {
+    let mut chars = string_lit.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' && chars.next() == Some('n') {
+            return true;
+        }
+    }
+    false
 }
 
 fn choose_separator_tactic(context: &RewriteContext<'_>, span: Span) -> Option<SeparatorTactic> {
@@ -1284,8 +1385,8 @@ pub(crate) fn can_be_overflowed_expr(
         // Handle always block-like expressions
         ast::ExprKind::Async(..) | ast::ExprKind::Block(..) | ast::ExprKind::Closure(..) => true,
 
-        // Handle `[]` and `{}`-like expressions
-        ast::ExprKind::Array(..) | ast::ExprKind::Struct(..) => {
+        // Handle `[]`, `{}` and `()`-like delimited expressions
+        ast::ExprKind::Array(..) | ast::ExprKind::Struct(..) | ast::ExprKind::Tup(..) => {
             context.config.overflow_delimited_expr()
                 || (context.use_block_indent() && args_len == 1)
         }
@@ -1301,7 +1402,7 @@ pub(crate) fn can_be_overflowed_expr(
         }
 
         // Handle parenthetical expressions
-        ast::ExprKind::Call(..) | ast::ExprKind::MethodCall(..) | ast::ExprKind::Tup(..) => {
+        ast::ExprKind::Call(..) | ast::ExprKind::MethodCall(..) => {
             context.use_block_indent() && args_len == 1
         }
 
@@ -1954,7 +2055,7 @@ fn choose_rhs<R: Rewrite>(
             let new_rhs = expr.rewrite(context, new_shape);
             let new_indent_str = &shape
                 .indent
-                .block_indent(context.config)
+                .continuation_indent(context.config)
                 .to_string_with_newline(context.config);
             let before_space_str = if has_rhs_comment { "" } else { " " };
 
@@ -1993,8 +2094,11 @@ fn shape_from_rhs_tactic(
             .with_max_width(context.config)
             .sub_width(shape.indent.width()),
         RhsTactics::Default | RhsTactics::AllowOverflow => {
-            Shape::indented(shape.indent.block_indent(context.config), context.config)
-                .sub_width(shape.rhs_overhead(context.config))
+            Shape::indented(
+                shape.indent.continuation_indent(context.config),
+                context.config,
+            )
+            .sub_width(shape.rhs_overhead(context.config))
         }
     }
 }
@@ -2051,7 +2155,11 @@ pub(crate) fn is_method_call(expr: &ast::Expr) -> bool {
 
 #[cfg(test)]
 mod test {
-    use super::last_line_offsetted;
+    use super::{
+        format_hex_literal_case, has_newline_escape, last_line_offsetted,
+        normalize_numeric_literal,
+    };
+    use crate::config::HexLiteralCase;
 
     #[test]
     fn test_last_line_offsetted() {
@@ -2073,4 +2181,41 @@ mod test {
         let lines = "one\n two      three";
         assert_eq!(last_line_offsetted(2, lines), false);
     }
+
+    #[test]
+    fn test_normalize_numeric_literal() {
+        assert_eq!(normalize_numeric_literal("0xFF"), "0xff");
+        assert_eq!(
+            normalize_numeric_literal("0XABCD_EF01u32"),
+            "0xabcd_ef01u32"
+        );
+        assert_eq!(normalize_numeric_literal("0x1F32"), "0x1f32");
+        assert_eq!(normalize_numeric_literal("0B1010_1010"), "0b1010_1010");
+        assert_eq!(normalize_numeric_literal("0o777i64"), "0o777i64");
+        assert_eq!(normalize_numeric_literal("1_000_000"), "1_000_000");
+        assert_eq!(normalize_numeric_literal("1000000usize"), "1000000usize");
+        assert_eq!(normalize_numeric_literal("1.5E10"), "1.5e10");
+        assert_eq!(normalize_numeric_literal("2E-3f64"), "2e-3f64");
+        assert_eq!(normalize_numeric_literal("1.0"), "1.0");
+    }
+
+    #[test]
+    fn test_format_hex_literal_case() {
+        let upper = HexLiteralCase::Upper;
+        let lower = HexLiteralCase::Lower;
+        assert_eq!(format_hex_literal_case("0xdead_beef", upper), "0xDEAD_BEEF");
+        assert_eq!(format_hex_literal_case("0xffu8", upper), "0xFFu8");
+        assert_eq!(format_hex_literal_case("0xABi64", lower), "0xabi64");
+        assert_eq!(format_hex_literal_case("0b1010", upper), "0b1010");
+        assert_eq!(format_hex_literal_case("0o17u32", upper), "0o17u32");
+        assert_eq!(format_hex_literal_case("1e10f64", upper), "1e10f64");
+    }
+
+    #[test]
+    fn test_has_newline_escape() {
+        assert!(has_newline_escape(r#""lorem\nipsum""#));
+        assert!(has_newline_escape(r#""lorem\\\nipsum""#));
+        assert!(!has_newline_escape(r#""lorem\\nipsum""#));
+        assert!(!has_newline_escape(r#""lorem\tnipsum""#));
+    }
 }
@@ -133,6 +133,7 @@ fn slice_annotation(error: &FormattingError) -> Option<SourceAnnotation<'_>> {
     }
 }
 
+#[allow(deprecated)]
 fn error_kind_to_snippet_annotation_type(error_kind: &ErrorKind) -> AnnotationType {
     match error_kind {
         ErrorKind::LineOverflow(..)
@@ -141,10 +142,15 @@ fn error_kind_to_snippet_annotation_type(error_kind: &ErrorKind) -> AnnotationTy
         | ErrorKind::ModuleResolutionError(_)
         | ErrorKind::ParseError
         | ErrorKind::LostComment
+        | ErrorKind::UnparsedCode
         | ErrorKind::LicenseCheck
         | ErrorKind::BadAttr
         | ErrorKind::InvalidGlobPattern(_)
+        | ErrorKind::InvalidFileDirective(..)
         | ErrorKind::VersionMismatch => AnnotationType::Error,
-        ErrorKind::BadIssue(_) | ErrorKind::DeprecatedAttr => AnnotationType::Warning,
+        ErrorKind::BadIssue(_)
+        | ErrorKind::MissingSafetyComment
+        | ErrorKind::UnreachableMatchArm
+        | ErrorKind::DeprecatedAttr => AnnotationType::Warning,
     }
 }
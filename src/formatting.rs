@@ -5,19 +5,23 @@ use std::io::{self, Write};
 use std::time::{Duration, Instant};
 
 use rustc_ast::ast;
-use rustc_span::Span;
+use rustc_errors::{Diagnostic, Level as DiagnosticLevel};
+use rustc_span::{BytePos, Span};
 
+use self::generated::is_generated_file;
 use self::newline_style::apply_newline_style;
 use crate::comment::{CharClasses, FullCodeCharKind};
-use crate::config::{Config, FileName, Verbosity};
-use crate::issues::BadIssueSeeker;
+use crate::config::{Config, EmitMode, FileName, ReportTactic, Verbosity};
+use crate::issues::{BadIssueSeeker, UnsafeSeeker};
+use crate::matches::report_unreachable_arms;
 use crate::modules::Module;
 use crate::syntux::parser::{DirectoryOwnership, Parser, ParserError};
-use crate::syntux::session::ParseSess;
-use crate::utils::count_newlines;
+use crate::syntux::session::{DiagnosticBuffer, ParseSess};
+use crate::utils::{count_newlines, mk_sp};
 use crate::visitor::FmtVisitor;
 use crate::{modules, source_file, ErrorKind, FormatReport, Input, Session};
 
+mod generated;
 mod newline_style;
 
 // A map of the files of a crate, with their new content
@@ -46,10 +50,12 @@ impl<'b, T: Write + 'b> Session<'b, T> {
             }
 
             let config = &self.config.clone();
-            let format_result = format_project(input, config, self, is_macro_def);
+            let diagnostics = self.diagnostics.clone();
+            let format_result = format_project(input, config, diagnostics, self, is_macro_def);
 
             format_result.map(|report| {
                 self.errors.add(&report.internal.borrow().1);
+                self.ignored_files.extend_from_slice(&report.ignored_files);
                 report
             })
         })
@@ -60,6 +66,7 @@ impl<'b, T: Write + 'b> Session<'b, T> {
 fn format_project<T: FormatHandler>(
     input: Input,
     config: &Config,
+    diagnostics: Option<DiagnosticBuffer>,
     handler: &mut T,
     is_macro_def: bool,
 ) -> Result<FormatReport, ErrorKind> {
@@ -68,9 +75,14 @@ fn format_project<T: FormatHandler>(
     let main_file = input.file_name();
     let input_is_stdin = main_file == FileName::Stdin;
 
-    let parse_session = ParseSess::new(config)?;
+    let mut parse_session = ParseSess::new(config, diagnostics)?;
     if config.skip_children() && parse_session.ignore_file(&main_file) {
-        return Ok(FormatReport::new());
+        should_emit_verbose(input_is_stdin, config, || {
+            println!("Skipping ignored file {}", main_file)
+        });
+        let mut report = FormatReport::new();
+        report.ignored_files.push(main_file);
+        return Ok(report);
     }
 
     // Parse the crate.
@@ -89,9 +101,9 @@ fn format_project<T: FormatHandler>(
         }
     };
 
-    let mut context = FormatContext::new(&krate, report, parse_session, config, handler);
+    let mut context = FormatContext::new(&krate, report, &parse_session, config, handler);
     let files = modules::ModResolver::new(
-        &context.parse_session,
+        context.parse_session,
         directory_ownership.unwrap_or(DirectoryOwnership::UnownedViaBlock),
         !input_is_stdin && !config.skip_children(),
     )
@@ -99,14 +111,38 @@ fn format_project<T: FormatHandler>(
 
     timer = timer.done_parsing();
 
+    let mut ignored_files = vec![];
+    let files = files
+        .into_iter()
+        .filter(|(path, module)| {
+            if config.skip_children() && *path != main_file {
+                return false;
+            }
+            if input_is_stdin {
+                return true;
+            }
+            if context.ignore_file(path) {
+                should_emit_verbose(false, config, || println!("Skipping ignored file {}", path));
+                ignored_files.push(path.clone());
+                return false;
+            }
+            !context.skip_generated_file(path, module)
+        })
+        .collect::<Vec<_>>();
+    context.report.ignored_files.append(&mut ignored_files);
+
+    // Report issues before error output is suppressed, so that they are emitted
+    // along with the other diagnostics.
+    for (path, module) in &files {
+        context.report_issues(path, module);
+    }
+
     // Suppress error output if we have to do any further parsing.
-    context.parse_session.set_silent_emitter();
+    let report = context.report;
+    let parse_session = parse_session.silence_errors();
+    let mut context = FormatContext::new(&krate, report, &parse_session, config, handler);
 
     for (path, module) in files {
-        let should_ignore = !input_is_stdin && context.ignore_file(&path);
-        if (config.skip_children() && path != main_file) || should_ignore {
-            continue;
-        }
         should_emit_verbose(input_is_stdin, config, || println!("Formatting {}", path));
         context.format_file(path, &module, is_macro_def)?;
     }
@@ -128,7 +164,7 @@ fn format_project<T: FormatHandler>(
 struct FormatContext<'a, T: FormatHandler> {
     krate: &'a ast::Crate,
     report: FormatReport,
-    parse_session: ParseSess,
+    parse_session: &'a ParseSess,
     config: &'a Config,
     handler: &'a mut T,
 }
@@ -138,6 +174,58 @@ impl<'a, T: FormatHandler + 'a> FormatContext<'a, T> {
         self.parse_session.ignore_file(path)
     }
 
+    // Returns `true` if the given file is marked as generated and should be left
+    // untouched because `format_generated_files` is disabled.
+    fn skip_generated_file(&self, path: &FileName, module: &Module<'_>) -> bool {
+        if self.config.format_generated_files() {
+            return false;
+        }
+        let snippet_provider = self.parse_session.snippet_provider(module.span);
+        let is_generated = is_generated_file(snippet_provider.entire_snippet());
+        if is_generated {
+            should_emit_verbose(false, self.config, || {
+                println!("Skipping generated file {}", path)
+            });
+        }
+        is_generated
+    }
+
+    // Emits a warning for each TODO and FIXME in the comments of the given file,
+    // depending on `report_todo` and `report_fixme`.
+    fn report_issues(&mut self, path: &FileName, module: &Module<'_>) {
+        let issue_seeker =
+            BadIssueSeeker::new(self.config.report_todo(), self.config.report_fixme());
+        if issue_seeker.is_disabled() {
+            return;
+        }
+
+        let snippet_provider = self.parse_session.snippet_provider(module.span);
+        let start_pos = snippet_provider.start_pos();
+        let diagnostics = issue_seeker
+            .find_in_comments(snippet_provider.entire_snippet())
+            .into_iter()
+            .filter_map(|(issue, range)| {
+                let span = mk_sp(
+                    start_pos + BytePos(range.start as u32),
+                    start_pos + BytePos(range.end as u32),
+                );
+                let line = self.parse_session.line_of_byte_pos(span.lo());
+                if !self.config.file_lines().contains_line(path, line) {
+                    return None;
+                }
+                let mut diagnostic =
+                    Diagnostic::new(DiagnosticLevel::Warning, &format!("found {}", issue));
+                diagnostic.set_span(span);
+                Some(diagnostic)
+            })
+            .collect::<Vec<_>>();
+
+        if !diagnostics.is_empty() {
+            self.report.add_check_error();
+            self.parse_session.emit_diagnostics(diagnostics);
+        }
+    }
+
     // Formats a single file/module.
     fn format_file(
         &mut self,
@@ -146,17 +234,31 @@ impl<'a, T: FormatHandler + 'a> FormatContext<'a, T> {
         is_macro_def: bool,
     ) -> Result<(), ErrorKind> {
         let snippet_provider = self.parse_session.snippet_provider(module.span);
+        // `// rustfmt-<option>: <value>` directives only apply to the file they are in.
+        let config = self
+            .config
+            .with_file_directives(snippet_provider.entire_snippet())
+            .map_err(|msg| ErrorKind::InvalidFileDirective(path.clone(), msg))?;
         let mut visitor = FmtVisitor::from_parse_sess(
-            &self.parse_session,
-            &self.config,
+            self.parse_session,
+            &config,
             &snippet_provider,
             self.report.clone(),
         );
         visitor.skip_context.update_with_attrs(&self.krate.attrs);
         visitor.is_macro_def = is_macro_def;
         visitor.last_pos = snippet_provider.start_pos();
+        // A shebang is not Rust code, so it is kept exactly as it is.
+        if let Some(len) = shebang_len(snippet_provider.entire_snippet()) {
+            visitor.push_str(&snippet_provider.entire_snippet()[..len]);
+            visitor.last_pos = visitor.last_pos + BytePos(len as u32);
+            visitor.skipped_range.borrow_mut().push((1, 1));
+        }
         visitor.skip_empty_lines(snippet_provider.end_pos());
         visitor.format_separate_mod(module, snippet_provider.end_pos());
+        if config.report_unreachable_match_arms() != ReportTactic::Never {
+            report_unreachable_arms(&module.items, &path, self.parse_session, &self.report);
+        }
 
         debug_assert_eq!(
             visitor.line_number,
@@ -173,12 +275,17 @@ impl<'a, T: FormatHandler + 'a> FormatContext<'a, T> {
             &mut visitor.buffer,
             &path,
             &visitor.skipped_range.borrow(),
-            &self.config,
+            &config,
             &self.report,
         );
 
+        if !config.newline_at_eof() {
+            let len = visitor.buffer.trim_end_matches('\n').len();
+            visitor.buffer.truncate(len);
+        }
+
         apply_newline_style(
-            self.config.newline_style(),
+            config.newline_style(),
             &mut visitor.buffer,
             snippet_provider.entire_snippet(),
         );
@@ -190,7 +297,7 @@ impl<'a, T: FormatHandler + 'a> FormatContext<'a, T> {
             .add_non_formatted_ranges(visitor.skipped_range.borrow().clone());
 
         self.handler.handle_formatted_file(
-            &self.parse_session,
+            self.parse_session,
             path,
             visitor.buffer.to_owned(),
             &mut self.report,
@@ -271,7 +378,8 @@ impl FormattingError {
             | ErrorKind::TrailingWhitespace
             | ErrorKind::IoError(_)
             | ErrorKind::ParseError
-            | ErrorKind::LostComment => true,
+            | ErrorKind::LostComment
+            | ErrorKind::UnparsedCode => true,
             _ => false,
         }
     }
@@ -286,14 +394,18 @@ impl FormattingError {
     }
 
     // (space, target)
+    #[allow(deprecated)]
     pub(crate) fn format_len(&self) -> (usize, usize) {
         match self.kind {
             ErrorKind::LineOverflow(found, max) => (max, found - max),
             ErrorKind::TrailingWhitespace
             | ErrorKind::DeprecatedAttr
             | ErrorKind::BadIssue(_)
+            | ErrorKind::MissingSafetyComment
+            | ErrorKind::UnreachableMatchArm
             | ErrorKind::BadAttr
             | ErrorKind::LostComment
+            | ErrorKind::UnparsedCode
             | ErrorKind::LicenseCheck => {
                 let trailing_ws_start = self
                     .line_buffer
@@ -422,7 +534,8 @@ fn format_lines(
     report: &FormatReport,
 ) {
     let mut formatter = FormatLines::new(name, skipped_range, config);
-    formatter.check_license(text);
+    let license = formatter.check_license(text);
+    formatter.find_unsafe_lines(text);
     formatter.iterate(text);
 
     if formatter.newline_count > 1 {
@@ -431,6 +544,15 @@ fn format_lines(
         text.truncate(line);
     }
 
+    // The license is inserted last, so that the line numbers of the skipped ranges still match.
+    // It is followed by a blank line, whether or not the template ends with one.
+    if let Some(license) = license {
+        let trailing_newlines = license.len() - license.trim_end_matches('\n').len();
+        let separator = "\n".repeat(2usize.saturating_sub(trailing_newlines));
+        text.insert_str(0, &separator);
+        text.insert_str(0, license);
+    }
+
     report.append(name.clone(), formatter.errors);
 }
 
@@ -442,11 +564,11 @@ struct FormatLines<'a> {
     cur_line: usize,
     newline_count: usize,
     errors: Vec<FormattingError>,
-    issue_seeker: BadIssueSeeker,
+    // The lines with an `unsafe` block or function which lacks a `SAFETY` comment.
+    unsafe_lines: Vec<usize>,
     line_buffer: String,
     current_line_contains_string_literal: bool,
     format_line: bool,
-    allow_issue_seek: bool,
     config: &'a Config,
 }
 
@@ -456,7 +578,6 @@ impl<'a> FormatLines<'a> {
         skipped_range: &'a [(usize, usize)],
         config: &'a Config,
     ) -> FormatLines<'a> {
-        let issue_seeker = BadIssueSeeker::new(config.report_todo(), config.report_fixme());
         FormatLines {
             name,
             skipped_range,
@@ -465,8 +586,7 @@ impl<'a> FormatLines<'a> {
             cur_line: 1,
             newline_count: 0,
             errors: vec![],
-            allow_issue_seek: !issue_seeker.is_disabled(),
-            issue_seeker,
+            unsafe_lines: vec![],
             line_buffer: String::with_capacity(config.max_width() * 2),
             current_line_contains_string_literal: false,
             format_line: config.file_lines().contains_line(name, 1),
@@ -474,17 +594,40 @@ impl<'a> FormatLines<'a> {
         }
     }
 
-    fn check_license(&mut self, text: &mut String) {
-        if let Some(ref license_template) = self.config.license_template {
-            if !license_template.is_match(text) {
-                self.errors.push(FormattingError {
-                    line: self.cur_line,
-                    kind: ErrorKind::LicenseCheck,
-                    is_comment: false,
-                    is_string: false,
-                    line_buffer: String::new(),
-                });
-            }
+    // Returns the license to insert at the start of `text` if it lacks one and
+    // `insert_license` is enabled. A license error is recorded for a mismatched
+    // header, and also for a missing one when checking, so that `--check` fails
+    // on it even though the license would be inserted.
+    fn check_license(&mut self, text: &str) -> Option<&'a str> {
+        let license_template = self.config.license_template.as_ref()?;
+        if license_template.is_match(text) {
+            return None;
+        }
+        let insert = if self.config.insert_license() && !has_header(text) {
+            self.config.license_text()
+        } else {
+            None
+        };
+        let check_only = match self.config.emit_mode() {
+            EmitMode::Diff | EmitMode::FilesWithDiff => true,
+            _ => false,
+        };
+        if insert.is_none() || check_only {
+            self.errors.push(FormattingError {
+                line: self.cur_line,
+                kind: ErrorKind::LicenseCheck,
+                is_comment: false,
+                is_string: false,
+                line_buffer: String::new(),
+            });
+        }
+        insert
+    }
+
+    fn find_unsafe_lines(&mut self, text: &str) {
+        let unsafe_seeker = UnsafeSeeker::new(self.config.report_unsafe_without_safety_comment());
+        if !unsafe_seeker.is_disabled() {
+            self.unsafe_lines = unsafe_seeker.find_in_code(text);
         }
     }
 
@@ -495,13 +638,6 @@ impl<'a> FormatLines<'a> {
                 continue;
             }
 
-            if self.allow_issue_seek && self.format_line {
-                // Add warnings for bad todos/ fixmes
-                if let Some(issue) = self.issue_seeker.inspect(c) {
-                    self.push_err(ErrorKind::BadIssue(issue), false, false);
-                }
-            }
-
             if c == '\n' {
                 self.new_line(kind);
             } else {
@@ -511,6 +647,13 @@ impl<'a> FormatLines<'a> {
     }
 
     fn new_line(&mut self, kind: FullCodeCharKind) {
+        if self.unsafe_lines.binary_search(&self.cur_line).is_ok()
+            && self.format_line
+            && !self.is_skipped_line()
+        {
+            self.push_err(ErrorKind::MissingSafetyComment, false, false);
+        }
+
         if self.format_line {
             // Check for (and record) trailing whitespace.
             if self.last_was_space {
@@ -585,7 +728,11 @@ impl<'a> FormatLines<'a> {
 
         match error_kind {
             ErrorKind::LineOverflow(..) => {
-                self.config.error_on_line_overflow() && allow_error_report
+                let allow_comment_report =
+                    !char_kind.is_comment() || self.config.error_on_line_overflow_comments();
+                let allow_string_report = !self.current_line_contains_string_literal
+                    || self.config.error_on_unformatted();
+                self.config.error_on_line_overflow() && allow_comment_report && allow_string_report
             }
             ErrorKind::TrailingWhitespace | ErrorKind::LostComment => allow_error_report,
             _ => true,
@@ -600,6 +747,32 @@ impl<'a> FormatLines<'a> {
     }
 }
 
+/// Returns the length of the shebang line (e.g., `#!/usr/bin/env run-cargo-script`) at the start
+/// of `src`, if there is one. The line ending is not included. `#!` followed by `[` starts an
+/// inner attribute rather than a shebang, just as it does for rustc.
+fn shebang_len(src: &str) -> Option<usize> {
+    let rest = src.strip_prefix("#!")?;
+    if rest.trim_start().starts_with('[') {
+        return None;
+    }
+    Some(src.find(&['\r', '\n'][..]).unwrap_or_else(|| src.len()))
+}
+
+// Whether `text` already starts with a header, i.e., a shebang or a comment which
+// isn't a doc comment, which a license shouldn't be inserted in front of.
+fn has_header(text: &str) -> bool {
+    if shebang_len(text).is_some() {
+        return true;
+    }
+    let is_doc = |s: &str| {
+        (s.starts_with("///") && !s.starts_with("////"))
+            || (s.starts_with("/**") && !s.starts_with("/***") && !s.starts_with("/**/"))
+            || s.starts_with("//!")
+            || s.starts_with("/*!")
+    };
+    (text.starts_with("//") || text.starts_with("/*")) && !is_doc(text)
+}
+
 fn should_emit_verbose<F>(forbid_verbose_output: bool, config: &Config, f: F)
 where
     F: Fn(),
@@ -0,0 +1,37 @@
+/// Returns `true` if the given source text is marked as generated, i.e., if its
+/// leading comment block contains the `@generated` marker.
+pub(super) fn is_generated_file(original_snippet: &str) -> bool {
+    original_snippet
+        .lines()
+        .map(str::trim)
+        .take_while(|line| {
+            line.is_empty()
+                || line.starts_with("//")
+                || line.starts_with("/*")
+                || line.starts_with('*')
+        })
+        .any(|line| line.contains("@generated"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::is_generated_file;
+
+    #[test]
+    fn detects_generated_marker_in_leading_comments() {
+        assert!(is_generated_file("// @generated by protoc\nfn main() {}\n"));
+        assert!(is_generated_file(
+            "// Copyright header\n\n/*\n * @generated\n */\nfn main() {}\n"
+        ));
+        assert!(is_generated_file("//! This file is @generated.\n"));
+    }
+
+    #[test]
+    fn ignores_generated_marker_after_leading_comments() {
+        assert!(!is_generated_file("fn main() {}\n"));
+        assert!(!is_generated_file("fn main() {}\n// @generated\n"));
+        assert!(!is_generated_file(
+            "// Copyright header\nuse foo;\n// @generated\n"
+        ));
+    }
+}
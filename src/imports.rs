@@ -184,15 +184,31 @@ pub(crate) fn merge_use_trees(use_trees: Vec<UseTree>, merge_by: SharedPrefix) -
 pub(crate) fn flatten_use_trees(use_trees: Vec<UseTree>) -> Vec<UseTree> {
     use_trees
         .into_iter()
-        .flat_map(UseTree::flatten)
+        .flat_map(|tree| {
+            if tree.has_comment() {
+                vec![tree]
+            } else {
+                tree.flatten()
+            }
+        })
         .map(|mut tree| {
-            // If a path ends in `::self`, rewrite it to `::{self}`.
+            // If a path ends in `::self`, rewrite it to the path of the module itself, e.g.,
+            // `a::b::self` to `a::b`. Paths which can't be imported on their own, such as `a`,
+            // `crate` or `super`, are rewritten to `::{self}` instead.
             if let Some(UseSegment::Slf(..)) = tree.path.last() {
                 let self_segment = tree.path.pop().unwrap();
-                tree.path.push(UseSegment::List(vec![UseTree::from_path(
-                    vec![self_segment],
-                    DUMMY_SP,
-                )]));
+                let len = tree.path.len();
+                match (tree.path.last_mut(), self_segment) {
+                    (Some(UseSegment::Ident(_, alias)), UseSegment::Slf(rename))
+                        if len > 1 && alias.is_none() =>
+                    {
+                        *alias = rename;
+                    }
+                    (_, self_segment) => {
+                        let self_tree = UseTree::from_path(vec![self_segment], DUMMY_SP);
+                        tree.path.push(UseSegment::List(vec![self_tree]));
+                    }
+                }
             }
             tree
         })
@@ -311,8 +327,8 @@ impl UseTree {
         item: &ast::Item,
     ) -> Option<UseTree> {
         match item.kind {
-            ast::ItemKind::Use(ref use_tree) => Some(
-                UseTree::from_ast(
+            ast::ItemKind::Use(ref use_tree) => {
+                let use_tree = UseTree::from_ast(
                     context,
                     use_tree,
                     None,
@@ -324,8 +340,13 @@ impl UseTree {
                         Some(item.attrs.clone())
                     },
                 )
-                .normalize(),
-            ),
+                .normalize();
+                if context.config.normalize_imports_self() {
+                    Some(use_tree.normalize_self())
+                } else {
+                    Some(use_tree)
+                }
+            }
             _ => None,
         }
     }
@@ -492,9 +513,9 @@ impl UseTree {
             return self;
         }
 
-        // Normalise foo::{bar} -> foo::bar
+        // Normalise foo::{bar} -> foo::bar, unless that would lose a comment.
         if let UseSegment::List(ref list) = last {
-            if list.len() == 1 && list[0].to_string() != "self" {
+            if list.len() == 1 && list[0].to_string() != "self" && !list[0].has_comment() {
                 normalize_sole_list = true;
             }
         }
@@ -522,6 +543,29 @@ impl UseTree {
         self
     }
 
+    // Normalise foo::{self} -> foo, here and in nested lists.
+    fn normalize_self(mut self) -> UseTree {
+        match self.path.pop() {
+            Some(UseSegment::List(list)) => {
+                let sole_self = list.len() == 1
+                    && list[0].to_string() == "self"
+                    && !list[0].has_comment()
+                    && matches!(self.path.last(), Some(UseSegment::Ident(_, None)));
+                if !sole_self {
+                    let mut list = list
+                        .into_iter()
+                        .map(UseTree::normalize_self)
+                        .collect::<Vec<_>>();
+                    list.sort();
+                    self.path.push(UseSegment::List(list));
+                }
+            }
+            Some(last) => self.path.push(last),
+            None => (),
+        }
+        self
+    }
+
     fn has_comment(&self) -> bool {
         self.list_item.as_ref().map_or(false, ListItem::has_comment)
     }
@@ -588,7 +632,8 @@ impl UseTree {
                             span: self.span,
                             list_item: None,
                             visibility: self.visibility.clone(),
-                            attrs: None,
+                            // Keep attributes such as `#[cfg]` on each of the flattened imports.
+                            attrs: self.attrs.clone(),
                         });
                     }
                 }
@@ -828,7 +873,10 @@ fn rewrite_nested_use_tree(
 
     let list_str = write_list(&list_items, &fmt)?;
 
-    let result = if (list_str.contains('\n') || list_str.len() > remaining_width)
+    // `Horizontal` keeps the list on one line, even if it doesn't fit.
+    let too_wide = list_str.len() > remaining_width
+        && context.config.imports_layout() != ListTactic::Horizontal;
+    let result = if (list_str.contains('\n') || too_wide)
         && context.config.imports_indent() == IndentStyle::Block
     {
         format!(
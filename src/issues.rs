@@ -1,9 +1,12 @@
 // Objects for seeking through a char stream for occurrences of TODO and FIXME.
 // Depending on the loaded configuration, may also check that these have an
-// associated issue number.
+// associated issue number. Also seeks through lines for `unsafe` blocks and
+// functions which are not preceded by a `SAFETY` comment.
 
 use std::fmt;
+use std::ops::Range;
 
+use crate::comment::{CharClasses, FullCodeCharKind};
 use crate::config::ReportTactic;
 
 const TO_DO_CHARS: &[char] = &['t', 'o', 'd', 'o'];
@@ -53,6 +56,15 @@ impl fmt::Display for Issue {
     }
 }
 
+impl Issue {
+    fn keyword_len(&self) -> usize {
+        match self.issue_type {
+            IssueType::Todo => TO_DO_CHARS.len(),
+            IssueType::Fixme => FIX_ME_CHARS.len(),
+        }
+    }
+}
+
 #[derive(PartialEq, Eq, Debug, Clone, Copy)]
 enum IssueType {
     Todo,
@@ -87,6 +99,26 @@ impl BadIssueSeeker {
         !is_enabled(self.report_todo) && !is_enabled(self.report_fixme)
     }
 
+    // Returns the bad issues in the comments of `text`, along with the byte range
+    // of their TODO or FIXME token.
+    pub(crate) fn find_in_comments(mut self, text: &str) -> Vec<(Issue, Range<usize>)> {
+        let mut issues = vec![];
+        let mut keyword = None;
+        for (kind, (pos, c)) in CharClasses::new(text.char_indices()) {
+            let was_seeking_issue = matches!(self.state, Seeking::Issue { .. });
+            let issue = self.inspect(c);
+            if let (true, Seeking::Number { issue, .. }) = (was_seeking_issue, self.state) {
+                let end = pos + c.len_utf8();
+                let start = end.saturating_sub(issue.keyword_len());
+                keyword = Some((start..end, kind.is_comment()));
+            }
+            if let (Some(issue), Some((range, true))) = (issue, keyword.clone()) {
+                issues.push((issue, range));
+            }
+        }
+        issues
+    }
+
     // Check whether or not the current char is conclusive evidence for an
     // unnumbered TO-DO or FIX-ME.
     pub(crate) fn inspect(&mut self, c: char) -> Option<Issue> {
@@ -211,6 +243,130 @@ impl BadIssueSeeker {
     }
 }
 
+const SAFETY_MARKER: &str = "SAFETY";
+
+pub(crate) struct UnsafeSeeker {
+    report_unsafe: ReportTactic,
+}
+
+// The code of a line, outside of comments and string literals, and whether the line has a
+// comment, and a `SAFETY` one.
+struct UnsafeSeekerLine {
+    code: String,
+    has_comment: bool,
+    has_safety_comment: bool,
+}
+
+impl UnsafeSeeker {
+    pub(crate) fn new(report_unsafe: ReportTactic) -> UnsafeSeeker {
+        UnsafeSeeker { report_unsafe }
+    }
+
+    pub(crate) fn is_disabled(&self) -> bool {
+        !is_enabled(self.report_unsafe)
+    }
+
+    // Returns the 1-based numbers of the lines of `text` which open an `unsafe` block or
+    // function without a `SAFETY` comment on the line itself or on the lines right above it.
+    // Like `BadIssueSeeker::find_in_comments`, `unsafe` is only looked for in code and `SAFETY`
+    // only in comments.
+    pub(crate) fn find_in_code(&self, text: &str) -> Vec<usize> {
+        let mut lines = vec![];
+        let mut line = UnsafeSeekerLine::new();
+        let mut comment = String::new();
+        for (kind, c) in CharClasses::new(text.chars()) {
+            if c == '\n' {
+                line.has_safety_comment = comment.contains(SAFETY_MARKER);
+                lines.push(std::mem::replace(&mut line, UnsafeSeekerLine::new()));
+                comment.clear();
+            } else if kind.is_comment() {
+                line.has_comment = true;
+                comment.push(c);
+            } else if kind.is_string() || kind == FullCodeCharKind::EndString {
+                line.code.push(' ');
+            } else {
+                line.code.push(c);
+            }
+        }
+        line.has_safety_comment = comment.contains(SAFETY_MARKER);
+        lines.push(line);
+
+        // The keyword and the block or `fn` may be on different lines.
+        let code = lines
+            .iter()
+            .map(|line| line.code.as_str())
+            .collect::<Vec<_>>()
+            .join("\n");
+        code.match_indices("unsafe")
+            .filter(|&(i, _)| is_unsafe_block_or_fn(&code, i))
+            .map(|(i, _)| code[..i].matches('\n').count())
+            .filter(|&i| !has_safety_comment(&lines[..=i]))
+            .map(|i| i + 1)
+            .collect()
+    }
+}
+
+impl UnsafeSeekerLine {
+    fn new() -> UnsafeSeekerLine {
+        UnsafeSeekerLine {
+            code: String::new(),
+            has_comment: false,
+            has_safety_comment: false,
+        }
+    }
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+// Returns `true` if the `unsafe` at `pos` in `code` is the keyword of an `unsafe { .. }` block
+// or of an `unsafe fn`, including `unsafe extern "ABI" fn`.
+fn is_unsafe_block_or_fn(code: &str, pos: usize) -> bool {
+    let before = code[..pos].chars().next_back();
+    let after = &code[pos + "unsafe".len()..];
+    if before.map_or(false, is_ident_char) || !after.starts_with(char::is_whitespace) {
+        return false;
+    }
+
+    let mut after = after.trim_start();
+    if let Some(abi) = strip_keyword(after, "extern") {
+        // The ABI string is blanked out, but for its opening quote.
+        after = abi.trim_start_matches(|c: char| c.is_whitespace() || c == '"');
+    }
+    after.starts_with('{') || strip_keyword(after, "fn").is_some()
+}
+
+// Returns the rest of `code` if it starts with the keyword `kw`.
+fn strip_keyword<'a>(code: &'a str, kw: &str) -> Option<&'a str> {
+    let rest = code.strip_prefix(kw)?;
+    if rest.starts_with(is_ident_char) {
+        None
+    } else {
+        Some(rest)
+    }
+}
+
+// Returns `true` if the last of `lines` has a `SAFETY` comment, or if one is found going up
+// through the lines which only hold comments or attributes.
+fn has_safety_comment(lines: &[UnsafeSeekerLine]) -> bool {
+    if lines.last().map_or(false, |line| line.has_safety_comment) {
+        return true;
+    }
+    for line in lines.iter().rev().skip(1) {
+        let code = line.code.trim();
+        let is_blank = code.is_empty() && !line.has_comment;
+        let is_item = !code.is_empty() && !code.starts_with('#');
+        if is_blank || is_item {
+            return false;
+        }
+        if line.has_safety_comment {
+            return true;
+        }
+    }
+    false
+}
+
 #[test]
 fn find_unnumbered_issue() {
     fn check_fail(text: &str, failing_pos: usize) {
@@ -283,6 +439,18 @@ fn find_issue() {
     ));
 }
 
+#[test]
+fn find_issue_in_comments() {
+    let seeker = BadIssueSeeker::new(ReportTactic::Unnumbered, ReportTactic::Always);
+    let text = "let todo = 1; // TODO(#1) and TODO\n/* FIXME(#2) */\n";
+    let issues = seeker
+        .find_in_comments(text)
+        .into_iter()
+        .map(|(_, range)| &text[range])
+        .collect::<Vec<_>>();
+    assert_eq!(issues, vec!["TODO", "FIXME"]);
+}
+
 #[test]
 fn issue_type() {
     let mut seeker = BadIssueSeeker::new(ReportTactic::Always, ReportTactic::Never);
@@ -315,3 +483,35 @@ fn issue_type() {
             .unwrap()
     );
 }
+
+#[test]
+fn find_unsafe_without_safety_comment() {
+    fn is_bad_unsafe(text: &str) -> bool {
+        let seeker = UnsafeSeeker::new(ReportTactic::Always);
+        !seeker.find_in_code(text).is_empty()
+    }
+
+    assert!(is_bad_unsafe("let x = unsafe { foo() };\n"));
+    assert!(is_bad_unsafe("pub unsafe fn foo() {}\n"));
+    assert!(is_bad_unsafe("unsafe extern \"C\" fn foo() {}\n"));
+    assert!(is_bad_unsafe("pub unsafe\nfn foo() {}\n"));
+    assert!(is_bad_unsafe("// SAFETY: fine\n\nunsafe { foo() }\n"));
+    assert!(is_bad_unsafe("let s = \"// SAFETY\";\nunsafe { foo() }\n"));
+    assert!(!is_bad_unsafe("// SAFETY: fine\nunsafe { foo() }\n"));
+    assert!(!is_bad_unsafe(
+        "// SAFETY: fine\n#[inline]\nunsafe fn foo() {}\n"
+    ));
+    assert!(!is_bad_unsafe(
+        "// SAFETY: fine\nunsafe extern \"C\" fn foo() {}\n"
+    ));
+    assert!(!is_bad_unsafe("// SAFETY: fine\npub unsafe\nfn foo() {}\n"));
+    assert!(!is_bad_unsafe("unsafe { foo() } // SAFETY: fine\n"));
+    assert!(!is_bad_unsafe("unsafe impl Send for Foo {}\n"));
+    assert!(!is_bad_unsafe("let not_unsafe = 1;\n"));
+    assert!(!is_bad_unsafe("let s = \"unsafe { foo() }\";\n"));
+    assert!(!is_bad_unsafe("// unsafe { foo() }\n"));
+
+    let seeker = UnsafeSeeker::new(ReportTactic::Always);
+    let text = "fn main() {\n    // SAFETY: fine\n    unsafe { foo() }\n    unsafe {\n    }\n}\n";
+    assert_eq!(seeker.find_in_code(text), vec![4]);
+}
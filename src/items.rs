@@ -1,7 +1,7 @@
 // Formatting top-level items - functions, structs, enums, traits, impls.
 
 use std::borrow::Cow;
-use std::cmp::{max, min, Ordering};
+use std::cmp::{max, min};
 
 use regex::Regex;
 use rustc_ast::visit;
@@ -11,8 +11,8 @@ use rustc_span::{symbol::kw, symbol::Ident, BytePos, Span, DUMMY_SP};
 use crate::attr::filter_inline_attrs;
 use crate::comment::{
     combine_strs_with_missing_comments, contains_comment, is_last_comment_block,
-    recover_comment_removed, recover_missing_comment_in_span, rewrite_missing_comment,
-    FindUncommented,
+    recover_comment_removed, recover_missing_comment_in_span, rewrite_comment,
+    rewrite_missing_comment, FindUncommented,
 };
 use crate::config::lists::*;
 use crate::config::{BraceStyle, Config, IndentStyle, Version};
@@ -28,6 +28,7 @@ use crate::shape::{Indent, Shape};
 use crate::source_map::{LineRangeUtils, SpanUtils};
 use crate::spanned::Spanned;
 use crate::stmt::Stmt;
+use crate::types::SupertraitBounds;
 use crate::utils::*;
 use crate::vertical::rewrite_with_alignment;
 use crate::visitor::FmtVisitor;
@@ -361,7 +362,7 @@ impl<'a> FmtVisitor<'a> {
 
         let context = self.get_context();
 
-        if self.config.empty_item_single_line()
+        if self.config.fn_empty_single_line_or_default()
             && is_empty_block(&context, block, None)
             && self.block_indent.width() + fn_str.len() + 3 <= self.config.max_width()
             && !last_line_contains_single_line_comment(fn_str)
@@ -369,10 +370,26 @@ impl<'a> FmtVisitor<'a> {
             return Some(format!("{} {{}}", fn_str));
         }
 
+        if self.config.fn_empty_single_line_or_default()
+            && self.config.version() == Version::Two
+            && block.stmts.is_empty()
+        {
+            if let Some(comment) = self.single_line_comment_body(block) {
+                let width = self.block_indent.width() + fn_str.len() + comment.len() + 5;
+                if width <= self.config.max_width()
+                    && !last_line_contains_single_line_comment(fn_str)
+                {
+                    return Some(format!("{} {{ {} }}", fn_str, comment));
+                }
+            }
+        }
+
         if !self.config.fn_single_line() || !is_simple_block_stmt(&context, block, None) {
             return None;
         }
 
+        let stripped_block = strip_tail_return(&context, block);
+        let block = stripped_block.as_ref().unwrap_or(block);
         let res = Stmt::from_ast_node(block.stmts.first()?, true)
             .rewrite(&self.get_context(), self.shape())?;
 
@@ -384,6 +401,24 @@ impl<'a> FmtVisitor<'a> {
         }
     }
 
+    // Returns the comment of a block such as `{ /* TODO */ }`, which holds nothing but a single
+    // block comment and is written on one line in the original source.
+    fn single_line_comment_body(&self, block: &ast::Block) -> Option<String> {
+        let snippet = self.snippet(block.span);
+        let inner = snippet.strip_prefix('{')?.strip_suffix('}')?.trim();
+        let is_single_comment = inner.starts_with("/*") && inner.find("*/")? + 2 == inner.len();
+        if inner.contains('\n') || !is_single_comment {
+            return None;
+        }
+        // The comment may be normalized into a line comment, which cannot precede the `}`.
+        let comment = rewrite_comment(inner, false, self.shape(), self.config)?;
+        if comment.starts_with("/*") && !comment.contains('\n') {
+            Some(comment)
+        } else {
+            None
+        }
+    }
+
     pub(crate) fn visit_static(&mut self, static_parts: &StaticParts<'_>) {
         let rewrite = rewrite_static(&self.get_context(), static_parts, self.block_indent);
         self.push_rewrite(static_parts.span, rewrite);
@@ -469,7 +504,7 @@ impl<'a> FmtVisitor<'a> {
         let discr_ident_lens: Vec<usize> = enum_def
             .variants
             .iter()
-            .filter(|var| var.disr_expr.is_some())
+            .filter(|var| var.disr_expr.is_some() && !has_non_doc_attrs(var))
             .map(|var| rewrite_ident(&self.get_context(), var.ident).len())
             .collect();
         // cut the list at the point of longest discrim shorter than the threshold
@@ -556,6 +591,12 @@ impl<'a> FmtVisitor<'a> {
         };
 
         let variant_body = if let Some(ref expr) = field.disr_expr {
+            // Variants with attributes other than doc comments are not aligned.
+            let pad_discrim_ident_to = if has_non_doc_attrs(field) {
+                0
+            } else {
+                pad_discrim_ident_to
+            };
             let lhs = format!("{:1$} =", variant_body, pad_discrim_ident_to);
             rewrite_assign_rhs_with(
                 &context,
@@ -568,7 +609,23 @@ impl<'a> FmtVisitor<'a> {
             variant_body
         };
 
-        combine_strs_with_missing_comments(&context, &attrs_str, &variant_body, span, shape, false)
+        let allow_extend = if field.attrs.len() == 1 {
+            let line_len = attrs_str.len() + 1 + variant_body.len();
+            !field.attrs[0].is_doc_comment()
+                && !variant_body.contains('\n')
+                && context.config.inline_attribute_width() >= line_len
+        } else {
+            false
+        };
+
+        combine_strs_with_missing_comments(
+            &context,
+            &attrs_str,
+            &variant_body,
+            span,
+            shape,
+            allow_extend,
+        )
     }
 
     fn visit_impl_items(&mut self, items: &[ptr::P<ast::AssocItem>]) {
@@ -625,25 +682,36 @@ impl<'a> FmtVisitor<'a> {
                 }
             }
 
-            buffer.sort_by(|(_, a), (_, b)| match (&a.kind, &b.kind) {
-                (TyAlias(lty), TyAlias(rty))
-                    if both_type(&lty.3, &rty.3) || both_opaque(&lty.3, &rty.3) =>
-                {
-                    a.ident.as_str().cmp(&b.ident.as_str())
+            fn kind_order(kind: &ast::AssocItemKind) -> u8 {
+                match kind {
+                    TyAlias(ty) if is_type(&ty.3) => 0,
+                    TyAlias(..) => 1,
+                    Const(..) => 2,
+                    MacCall(..) => 3,
+                    Fn(..) => 4,
                 }
-                (Const(..), Const(..)) | (MacCall(..), MacCall(..)) => {
-                    a.ident.as_str().cmp(&b.ident.as_str())
+            }
+
+            // Items are only bucketed by kind, keeping their source order within each bucket.
+            // Items marked with `#[rustfmt::skip]` stay where they are.
+            let anchored: Vec<bool> = buffer
+                .iter()
+                .map(|(_, item)| contains_skip(&item.attrs))
+                .collect();
+            let (fixed, mut movable): (Vec<_>, Vec<_>) = buffer
+                .into_iter()
+                .partition(|(_, item)| contains_skip(&item.attrs));
+            movable.sort_by_key(|(_, item)| kind_order(&item.kind));
+            let mut fixed = fixed.into_iter();
+            let mut movable = movable.into_iter();
+            let buffer = anchored.into_iter().filter_map(|is_anchored| {
+                if is_anchored {
+                    fixed.next()
+                } else {
+                    movable.next()
                 }
-                (Fn(..), Fn(..)) => a.span.lo().cmp(&b.span.lo()),
-                (TyAlias(ty), _) if is_type(&ty.3) => Ordering::Less,
-                (_, TyAlias(ty)) if is_type(&ty.3) => Ordering::Greater,
-                (TyAlias(..), _) => Ordering::Less,
-                (_, TyAlias(..)) => Ordering::Greater,
-                (Const(..), _) => Ordering::Less,
-                (_, Const(..)) => Ordering::Greater,
-                (MacCall(..), _) => Ordering::Less,
-                (_, MacCall(..)) => Ordering::Greater,
             });
+
             let mut prev_kind = None;
             for (buf, item) in buffer {
                 // Make sure that there are at least a single empty line between
@@ -790,7 +858,7 @@ pub(crate) fn format_impl(
             result.push_str(&inner_indent_str);
             result.push_str(visitor.buffer.trim());
             result.push_str(&outer_indent_str);
-        } else if need_newline || !context.config.empty_item_single_line() {
+        } else if need_newline || !context.config.impl_empty_single_line_or_default() {
             result.push_str(&sep);
         }
 
@@ -813,7 +881,7 @@ fn is_impl_single_line(
     let open_pos = snippet.find_uncommented("{")? + 1;
 
     Some(
-        context.config.empty_item_single_line()
+        context.config.impl_empty_single_line_or_default()
             && items.is_empty()
             && !result.contains('\n')
             && result.len() + where_clause_str.len() <= context.config.max_width()
@@ -1092,7 +1160,7 @@ pub(crate) fn format_trait(
             result = rewrite_assign_rhs_with(
                 context,
                 result + ":",
-                generic_bounds,
+                &SupertraitBounds(generic_bounds),
                 shape,
                 RhsTactics::ForceNextLineWithoutIndent,
             )?;
@@ -1196,7 +1264,7 @@ pub(crate) fn format_trait(
             result.push_str(&inner_indent_str);
             result.push_str(visitor.buffer.trim());
             result.push_str(&outer_indent_str);
-        } else if result.contains('\n') {
+        } else if result.contains('\n') || !context.config.empty_item_single_line() {
             result.push_str(&outer_indent_str);
         }
 
@@ -1423,7 +1491,12 @@ fn format_empty_struct_or_tuple(
     }
     result.push_str(opener);
     match rewrite_missing_comment(span, Shape::indented(offset, context.config), context) {
-        Some(ref s) if s.is_empty() => (),
+        Some(ref s) if s.is_empty() => {
+            // Empty braced bodies are only kept on one line with `empty_item_single_line`.
+            if closer == "}" && !context.config.empty_item_single_line() {
+                result.push_str(&offset.to_string_with_newline(context.config));
+            }
+        }
         Some(ref s) => {
             if !is_single_line(s) || first_line_contains_single_line_comment(s) {
                 let nested_indent_str = offset
@@ -1749,6 +1822,10 @@ pub(crate) fn rewrite_struct_field(
     combine_strs_with_missing_comments(context, &attrs_str, field_str, missing_span, shape, false)
 }
 
+fn has_non_doc_attrs(variant: &ast::Variant) -> bool {
+    variant.attrs.iter().any(|attr| !attr.is_doc_comment())
+}
+
 pub(crate) struct StaticParts<'a> {
     prefix: &'a str,
     vis: &'a ast::Visibility,
@@ -2211,18 +2288,25 @@ fn rewrite_fn_base(
         offset: used_width,
     };
     let fd = fn_sig.decl;
-    let generics_str = rewrite_generics(
-        context,
-        rewrite_ident(context, ident),
-        fn_sig.generics,
-        shape,
-    )?;
+    let generics_space = context.config.fn_generics_space();
+    let has_generics = !fn_sig.generics.params.is_empty();
+    let ident_str = if has_generics && generics_space.space_before() {
+        Cow::from(format!("{} ", rewrite_ident(context, ident)))
+    } else {
+        Cow::from(rewrite_ident(context, ident))
+    };
+    let generics_str = rewrite_generics(context, &ident_str, fn_sig.generics, shape)?;
     result.push_str(&generics_str);
 
     let snuggle_angle_bracket = generics_str
         .lines()
         .last()
         .map_or(false, |l| l.trim_start().len() == 1);
+    if has_generics && generics_space.space_after() {
+        result.push(' ');
+    } else if context.config.space_before_fn_paren() {
+        result.push(' ');
+    }
 
     // Note that the width and indent don't really matter, we'll re-layout the
     // return type later anyway.
@@ -2490,7 +2574,23 @@ fn rewrite_fn_base(
         }
     }
 
-    result.push_str(&where_clause_str);
+    match where_clause_str.trim_start() {
+        // Keep a single-line where-clause on the same line as the signature if it fits.
+        // 2 = ` {`
+        single_line_where
+            if context.config.where_single_line()
+                && !is_params_multi_lined
+                && !result.contains('\n')
+                && single_line_where.starts_with("where ")
+                && !single_line_where.contains('\n')
+                && last_line_width(&result) + 1 + single_line_where.len() + 2
+                    <= context.config.max_width() =>
+        {
+            result.push(' ');
+            result.push_str(single_line_where);
+        }
+        _ => result.push_str(&where_clause_str),
+    }
 
     let ends_with_comment = last_line_contains_single_line_comment(&result);
     force_new_line_for_brace |= ends_with_comment;
@@ -2600,7 +2700,7 @@ fn rewrite_params(
         &param_items,
         context
             .config
-            .fn_args_layout()
+            .fn_params_layout()
             .to_list_tactic(param_items.len()),
         Separator::Comma,
         one_line_budget,
@@ -5,7 +5,6 @@
 
 #[macro_use]
 extern crate derive_new;
-#[cfg(test)]
 #[macro_use]
 extern crate lazy_static;
 #[macro_use]
@@ -22,7 +21,7 @@ extern crate rustc_session;
 extern crate rustc_span;
 
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 use std::io::{self, Write};
 use std::mem;
@@ -32,7 +31,7 @@ use std::rc::Rc;
 
 use ignore;
 use rustc_ast::ast;
-use rustc_span::symbol;
+use rustc_span::{symbol, Span};
 use thiserror::Error;
 
 use crate::comment::LineClasses;
@@ -42,11 +41,12 @@ use crate::issues::Issue;
 use crate::modules::ModuleResolutionError;
 use crate::shape::Indent;
 use crate::syntux::parser::DirectoryOwnership;
+use crate::syntux::session::DiagnosticBuffer;
 use crate::utils::indent_next_line;
 
 pub use crate::config::{
-    load_config, CliOptions, Color, Config, Edition, EmitMode, FileLines, FileName, NewlineStyle,
-    Range, Verbosity,
+    is_valid_backup_suffix, load_config, CliOptions, Color, Config, Edition, EmitMode, FileLines,
+    FileName, NewlineStyle, Range, Verbosity,
 };
 
 pub use crate::format_report_formatter::{FormatReportFormatter, FormatReportFormatterBuilder};
@@ -110,8 +110,15 @@ pub enum ErrorKind {
     #[error("left behind trailing whitespace")]
     TrailingWhitespace,
     /// TODO or FIXME item without an issue number.
+    #[deprecated(note = "TODO and FIXME items are reported as diagnostics instead")]
     #[error("found {0}")]
     BadIssue(Issue),
+    /// `unsafe` block or function without a `SAFETY` comment.
+    #[error("found `unsafe` without a `SAFETY` comment")]
+    MissingSafetyComment,
+    /// Match arm after a catch-all arm.
+    #[error("match arm after a catch-all arm is unreachable")]
+    UnreachableMatchArm,
     /// License check has failed.
     #[error("license check failed")]
     LicenseCheck,
@@ -137,9 +144,16 @@ pub enum ErrorKind {
     /// If we had formatted the given node, then we would have lost a comment.
     #[error("not formatted because a comment would be lost")]
     LostComment,
+    /// The given node was left as is because it could not be parsed, e.g., the
+    /// arguments of a macro call.
+    #[error("not formatted because the code could not be parsed")]
+    UnparsedCode,
     /// Invalid glob pattern in `ignore` configuration option.
     #[error("Invalid glob pattern found in ignore list: {0}")]
     InvalidGlobPattern(ignore::Error),
+    /// Invalid `// rustfmt-<option>: <value>` directive at the start of a file.
+    #[error("invalid `rustfmt-` directive in {0}: {1}")]
+    InvalidFileDirective(FileName, String),
 }
 
 impl ErrorKind {
@@ -193,6 +207,11 @@ pub struct FormatReport {
     // Maps stringified file paths to their associated formatting errors.
     internal: Rc<RefCell<(FormatErrorMap, ReportedErrors)>>,
     non_formatted_ranges: Vec<(usize, usize)>,
+    // Files which weren't formatted because they match the `ignore` option.
+    ignored_files: Vec<FileName>,
+    // Code which was left as is because it couldn't be parsed. Its rewrite may be attempted
+    // several times, but it is reported once.
+    unparsed_code: Rc<RefCell<HashSet<Span>>>,
 }
 
 impl FormatReport {
@@ -200,9 +219,16 @@ impl FormatReport {
         FormatReport {
             internal: Rc::new(RefCell::new((HashMap::new(), ReportedErrors::default()))),
             non_formatted_ranges: Vec::new(),
+            ignored_files: Vec::new(),
+            unparsed_code: Rc::new(RefCell::new(HashSet::new())),
         }
     }
 
+    /// The files which weren't formatted because they match the `ignore` option.
+    pub fn ignored_files(&self) -> &[FileName] {
+        &self.ignored_files
+    }
+
     fn add_non_formatted_ranges(&mut self, mut ranges: Vec<(usize, usize)>) {
         self.non_formatted_ranges.append(&mut ranges);
     }
@@ -235,10 +261,15 @@ impl FormatReport {
                     errs.has_operational_errors = true;
                     errs.has_unformatted_code_errors = true;
                 }
-                ErrorKind::LostComment => {
+                ErrorKind::LostComment | ErrorKind::UnparsedCode => {
                     errs.has_unformatted_code_errors = true;
                 }
-                ErrorKind::BadIssue(_)
+                #[allow(deprecated)]
+                ErrorKind::BadIssue(_) => {
+                    errs.has_check_errors = true;
+                }
+                ErrorKind::MissingSafetyComment
+                | ErrorKind::UnreachableMatchArm
                 | ErrorKind::LicenseCheck
                 | ErrorKind::DeprecatedAttr
                 | ErrorKind::BadAttr
@@ -262,6 +293,15 @@ impl FormatReport {
         self.internal.borrow_mut().1.has_parsing_errors = true;
     }
 
+    // Returns `true` unless the code in `span` has already been reported as unparsed.
+    fn add_unparsed_code(&self, span: Span) -> bool {
+        self.unparsed_code.borrow_mut().insert(span)
+    }
+
+    fn add_check_error(&mut self) {
+        self.internal.borrow_mut().1.has_check_errors = true;
+    }
+
     fn warning_count(&self) -> usize {
         self.internal
             .borrow()
@@ -314,6 +354,9 @@ fn format_snippet(snippet: &str, config: &Config, is_macro_def: bool) -> Option<
         config.set().emit_mode(config::EmitMode::Stdout);
         config.set().verbose(Verbosity::Quiet);
         config.set().hide_parse_errors(true);
+        // The issues in the snippet are reported along with the rest of the enclosing file.
+        config.set().report_todo(config::ReportTactic::Never);
+        config.set().report_fixme(config::ReportTactic::Never);
         if is_macro_def {
             config.set().error_on_unformatted(true);
         }
@@ -435,6 +478,45 @@ fn format_code_block(
     })
 }
 
+/// The result of formatting a string of Rust source code with [`format_string`].
+#[derive(Clone)]
+pub struct FormattedString {
+    /// The formatted source code.
+    pub formatted: String,
+    /// Any issues that occurred while formatting the source code.
+    pub report: FormatReport,
+}
+
+/// Formats the given Rust source code in memory according to the given config.
+///
+/// Unlike [`Session::format`], this never writes to the filesystem or to stdout, and
+/// out-of-line modules are not resolved. Parse errors are not printed; they are returned
+/// as [`ErrorKind::ParseError`].
+pub fn format_string(input: &str, config: &Config) -> Result<FormattedString, ErrorKind> {
+    if config.disable_all_formatting() {
+        return Ok(FormattedString {
+            formatted: input.to_owned(),
+            report: FormatReport::new(),
+        });
+    }
+
+    let mut config = config.clone();
+    config.set().verbose(Verbosity::Quiet);
+    config.set().hide_parse_errors(true);
+
+    let mut session = Session::<io::Sink>::new(config, None);
+    let report = session.format(Input::Text(input.to_owned()))?;
+    if session.has_parsing_errors() {
+        return Err(ErrorKind::ParseError);
+    }
+
+    let formatted = mem::take(&mut session.source_file)
+        .into_iter()
+        .next()
+        .map_or_else(String::new, |(_, text)| text);
+    Ok(FormattedString { formatted, report })
+}
+
 /// A session is a run of rustfmt across a single or multiple inputs.
 pub struct Session<'b, T: Write> {
     pub config: Config,
@@ -442,6 +524,8 @@ pub struct Session<'b, T: Write> {
     pub(crate) errors: ReportedErrors,
     source_file: SourceFile,
     emitter: Box<dyn Emitter + 'b>,
+    ignored_files: Vec<FileName>,
+    diagnostics: Option<DiagnosticBuffer>,
 }
 
 impl<'b, T: Write + 'b> Session<'b, T> {
@@ -458,6 +542,8 @@ impl<'b, T: Write + 'b> Session<'b, T> {
             emitter,
             errors: ReportedErrors::default(),
             source_file: SourceFile::new(),
+            ignored_files: Vec::new(),
+            diagnostics: None,
         }
     }
 
@@ -477,6 +563,25 @@ impl<'b, T: Write + 'b> Session<'b, T> {
         result
     }
 
+    /// Collects the diagnostics of the parser and the warnings about the source, instead of
+    /// printing them to stderr. They are returned by `take_diagnostics`.
+    pub fn capture_diagnostics(&mut self) {
+        self.diagnostics = Some(DiagnosticBuffer::default());
+    }
+
+    /// Returns the diagnostics collected since the last call, if `capture_diagnostics` was
+    /// called.
+    pub fn take_diagnostics(&mut self) -> Vec<u8> {
+        self.diagnostics
+            .as_ref()
+            .map_or_else(Vec::new, DiagnosticBuffer::take)
+    }
+
+    /// The files skipped so far because they match the `ignore` option.
+    pub fn ignored_files(&self) -> &[FileName] {
+        &self.ignored_files
+    }
+
     pub fn add_operational_error(&mut self) {
         self.errors.has_operational_errors = true;
     }
@@ -519,7 +624,7 @@ impl<'b, T: Write + 'b> Session<'b, T> {
 pub(crate) fn create_emitter<'a>(config: &Config) -> Box<dyn Emitter + 'a> {
     match config.emit_mode() {
         EmitMode::Files if config.make_backup() => {
-            Box::new(emitter::FilesWithBackupEmitter::default())
+            Box::new(emitter::FilesWithBackupEmitter::new(config.backup_suffix()))
         }
         EmitMode::Files => Box::new(emitter::FilesEmitter::new(
             config.print_misformatted_file_names(),
@@ -530,7 +635,13 @@ pub(crate) fn create_emitter<'a>(config: &Config) -> Box<dyn Emitter + 'a> {
         EmitMode::Json => Box::new(emitter::JsonEmitter::default()),
         EmitMode::ModifiedLines => Box::new(emitter::ModifiedLinesEmitter::default()),
         EmitMode::Checkstyle => Box::new(emitter::CheckstyleEmitter::default()),
+        EmitMode::Diff if config.print_misformatted_file_names() => {
+            Box::new(emitter::FilesWithDiffEmitter::new(false))
+        }
         EmitMode::Diff => Box::new(emitter::DiffEmitter::new(config.clone())),
+        EmitMode::FilesWithDiff => Box::new(emitter::FilesWithDiffEmitter::new(
+            config.null_separated_file_names(),
+        )),
     }
 }
 
@@ -610,6 +721,21 @@ mod unit_tests {
         assert!(test_format_inner(format_snippet, snippet, expected));
     }
 
+    #[test]
+    fn test_format_string() {
+        let mut config = Config::default();
+        config.set().newline_style(NewlineStyle::Unix);
+        let result = format_string("fn main ( ) { let x=3; }", &config).unwrap();
+        assert_eq!(result.formatted, "fn main() {\n    let x = 3;\n}\n");
+        assert!(!result.report.has_warnings());
+    }
+
+    #[test]
+    fn test_format_string_parse_error() {
+        let result = format_string("fn main(", &Config::default());
+        assert!(matches!(result, Err(ErrorKind::ParseError)));
+    }
+
     #[test]
     fn test_format_code_block_fail() {
         #[rustfmt::skip]
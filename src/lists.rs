@@ -27,6 +27,8 @@ pub(crate) struct ListFormatting<'a> {
     ends_with_newline: bool,
     // Remove newlines between list elements for expressions.
     preserve_newline: bool,
+    // The maximum number of blank lines kept between list elements when preserving newlines.
+    max_blank_lines: usize,
     // Nested import lists get some special handling for the "Mixed" list type
     nested: bool,
     // Whether comments should be visually aligned.
@@ -44,6 +46,7 @@ impl<'a> ListFormatting<'a> {
             shape,
             ends_with_newline: true,
             preserve_newline: false,
+            max_blank_lines: 1,
             nested: false,
             align_comments: true,
             config,
@@ -80,6 +83,11 @@ impl<'a> ListFormatting<'a> {
         self
     }
 
+    pub(crate) fn max_blank_lines(mut self, max_blank_lines: usize) -> Self {
+        self.max_blank_lines = max_blank_lines;
+        self
+    }
+
     pub(crate) fn nested(mut self, nested: bool) -> Self {
         self.nested = nested;
         self
@@ -127,8 +135,8 @@ pub(crate) struct ListItem {
     // rewrite.
     pub(crate) item: Option<String>,
     pub(crate) post_comment: Option<String>,
-    // Whether there is extra whitespace before this item.
-    pub(crate) new_lines: bool,
+    // The number of blank lines between this item and the next one.
+    pub(crate) blank_lines: usize,
 }
 
 impl ListItem {
@@ -138,7 +146,7 @@ impl ListItem {
             pre_comment_style: ListItemCommentStyle::None,
             item: None,
             post_comment: None,
-            new_lines: false,
+            blank_lines: 0,
         }
     }
 
@@ -187,7 +195,7 @@ impl ListItem {
             pre_comment_style: ListItemCommentStyle::None,
             item: Some(s.into()),
             post_comment: None,
-            new_lines: false,
+            blank_lines: 0,
         }
     }
 
@@ -503,13 +511,15 @@ where
             item_max_width = None;
         }
 
-        if formatting.preserve_newline
-            && !last
-            && tactic == DefinitiveListTactic::Vertical
-            && item.new_lines
-        {
+        let blank_lines =
+            if formatting.preserve_newline && !last && tactic == DefinitiveListTactic::Vertical {
+                cmp::min(item.blank_lines, formatting.max_blank_lines)
+            } else {
+                0
+            };
+        if blank_lines > 0 {
             item_max_width = None;
-            result.push('\n');
+            result.push_str(&"\n".repeat(blank_lines));
         }
 
         prev_item_had_post_comment = item.post_comment.is_some();
@@ -544,7 +554,7 @@ where
         if max_width < inner_item_width {
             max_width = inner_item_width;
         }
-        if item.new_lines {
+        if item.blank_lines > 0 {
             return max_width;
         }
         first = false;
@@ -694,9 +704,9 @@ pub(crate) fn get_comment_end(
 
 // Account for extra whitespace between items. This is fiddly
 // because of the way we divide pre- and post- comments.
-pub(crate) fn has_extra_newline(post_snippet: &str, comment_end: usize) -> bool {
+pub(crate) fn count_blank_lines(post_snippet: &str, comment_end: usize) -> usize {
     if post_snippet.is_empty() || comment_end == 0 {
-        return false;
+        return 0;
     }
 
     let len_last = post_snippet[..comment_end]
@@ -717,8 +727,8 @@ pub(crate) fn has_extra_newline(post_snippet: &str, comment_end: usize) -> bool
     // From the end of the first line of comments to the next non-whitespace char.
     let test_snippet = &test_snippet[..first];
 
-    // There were multiple line breaks which got trimmed to nothing.
-    count_newlines(test_snippet) > 1
+    // Every line break after the first one was a blank line which got trimmed to nothing.
+    count_newlines(test_snippet).saturating_sub(1)
 }
 
 impl<'a, T, I, F1, F2, F3> Iterator for ListItems<'a, I, F1, F2, F3>
@@ -754,7 +764,7 @@ where
                 self.terminator,
                 self.inner.peek().is_none(),
             );
-            let new_lines = has_extra_newline(post_snippet, comment_end);
+            let blank_lines = count_blank_lines(post_snippet, comment_end);
             let post_comment = extract_post_comment(post_snippet, comment_end, self.separator);
 
             self.prev_span_end = (self.get_hi)(&item) + BytePos(comment_end as u32);
@@ -768,7 +778,7 @@ where
                     (self.get_item_string)(&item)
                 },
                 post_comment,
-                new_lines,
+                blank_lines,
             }
         })
     }
@@ -863,7 +873,14 @@ pub(crate) fn struct_lit_shape(
     };
     let shape_width = shape.width.checked_sub(prefix_width + suffix_width);
     if let Some(w) = shape_width {
-        let shape_width = cmp::min(w, context.config.struct_lit_width());
+        // `struct_lit_width` only limits the body, while `struct_lit_single_line_width` limits
+        // the whole literal, including the prefix (e.g., `Foo { `) and the suffix (` }`).
+        let mut shape_width = cmp::min(w, context.config.struct_lit_width());
+        let single_line_width = context.config.struct_lit_single_line_width();
+        if single_line_width > 0 {
+            let body_width = single_line_width.saturating_sub(prefix_width + suffix_width);
+            shape_width = cmp::min(shape_width, body_width);
+        }
         Some((Some(Shape::legacy(shape_width, shape.indent)), v_shape))
     } else {
         Some((None, v_shape))
@@ -923,6 +940,7 @@ pub(crate) fn struct_lit_formatting<'a>(
         shape,
         ends_with_newline,
         preserve_newline: true,
+        max_blank_lines: 1,
         nested: false,
         align_comments: true,
         config: context.config,
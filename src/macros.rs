@@ -39,6 +39,7 @@ use crate::utils::{
     rewrite_ident, trim_left_preserve_layout, wrap_str, NodeIdExt,
 };
 use crate::visitor::FmtVisitor;
+use crate::{ErrorKind, FormattingError};
 
 const FORCED_BRACKET_MACROS: &[&str] = &["vec!"];
 
@@ -171,6 +172,7 @@ fn return_macro_parse_failure_fallback(
 ) -> Option<String> {
     // Mark this as a failure however we format it
     context.macro_rewrite_failure.replace(true);
+    report_unparsed_code(context, span);
 
     // Heuristically determine whether the last line of the macro uses "Block" style
     // rather than using "Visual" style, or another indentation style.
@@ -198,6 +200,22 @@ fn return_macro_parse_failure_fallback(
     Some(context.snippet(span).to_owned())
 }
 
+// Reports that the code in `span` was left as is because it could not be parsed. The macro
+// may be rewritten several times, e.g., while trying different layouts for the expression which
+// contains it, so only the first report of `span` is kept.
+fn report_unparsed_code(context: &RewriteContext<'_>, span: Span) {
+    if context.config.error_on_unformatted() && context.report.add_unparsed_code(span) {
+        context.report.append(
+            context.parse_sess.span_to_filename(span),
+            vec![FormattingError::from_span(
+                span,
+                &context.parse_sess,
+                ErrorKind::UnparsedCode,
+            )],
+        );
+    }
+}
+
 pub(crate) fn rewrite_macro(
     mac: &ast::MacCall,
     extra_ident: Option<symbol::Ident>,
@@ -269,25 +287,39 @@ fn rewrite_macro_inner(
     let original_style = macro_style(mac, context);
 
     let macro_name = rewrite_macro_name(context, &mac.path, extra_ident);
+    let is_forced_bracket_macro = FORCED_BRACKET_MACROS.contains(&&macro_name[..]);
 
-    let style = if FORCED_BRACKET_MACROS.contains(&&macro_name[..]) && !is_nested_macro {
+    let style = if is_forced_bracket_macro && !is_nested_macro {
         DelimToken::Bracket
     } else {
         original_style
     };
 
+    // The callee is written before the delimiter, so it carries the space of
+    // `space_after_macro_bang` while `macro_name` itself stays unpadded. Brace-delimited macro
+    // calls always have a space before the `{`, and the space does not apply to macros with an
+    // extra identifier (e.g., `macro_rules! foo`) as the delimiter does not follow the `!`.
+    let callee = match style {
+        DelimToken::Paren | DelimToken::Bracket
+            if context.config.space_after_macro_bang() && macro_name.ends_with('!') =>
+        {
+            format!("{} ", macro_name)
+        }
+        _ => macro_name.clone(),
+    };
+
     let ts = mac.args.inner_tokens();
     let has_comment = contains_comment(context.snippet(mac.span()));
     if ts.is_empty() && !has_comment {
         return match style {
             DelimToken::Paren if position == MacroPosition::Item => {
-                Some(format!("{}();", macro_name))
+                Some(format!("{}();", callee))
             }
             DelimToken::Bracket if position == MacroPosition::Item => {
-                Some(format!("{}[];", macro_name))
+                Some(format!("{}[];", callee))
             }
-            DelimToken::Paren => Some(format!("{}()", macro_name)),
-            DelimToken::Bracket => Some(format!("{}[]", macro_name)),
+            DelimToken::Paren => Some(format!("{}()", callee)),
+            DelimToken::Bracket => Some(format!("{}[]", callee)),
             DelimToken::Brace => Some(format!("{} {{}}", macro_name)),
             _ => unreachable!(),
         };
@@ -319,7 +351,7 @@ fn rewrite_macro_inner(
                 TokenKind::Comma => (),
                 TokenKind::Semi => {
                     // Try to parse `vec![expr; expr]`
-                    if FORCED_BRACKET_MACROS.contains(&&macro_name[..]) {
+                    if is_forced_bracket_macro {
                         parser.bump();
                         if parser.token.kind != TokenKind::Eof {
                             match parse_macro_arg(&mut parser) {
@@ -360,7 +392,7 @@ fn rewrite_macro_inner(
         return rewrite_macro_with_items(
             context,
             &arg_vec,
-            &macro_name,
+            &callee,
             shape,
             style,
             position,
@@ -372,13 +404,13 @@ fn rewrite_macro_inner(
         DelimToken::Paren => {
             // Handle special case: `vec!(expr; expr)`
             if vec_with_semi {
-                handle_vec_semi(context, shape, arg_vec, macro_name, style)
+                handle_vec_semi(context, shape, arg_vec, callee, style)
             } else {
                 // Format macro invocation as function call, preserve the trailing
                 // comma because not all macros support them.
                 overflow::rewrite_with_parens(
                     context,
-                    &macro_name,
+                    &callee,
                     arg_vec.iter(),
                     shape,
                     mac.span(),
@@ -398,25 +430,25 @@ fn rewrite_macro_inner(
         DelimToken::Bracket => {
             // Handle special case: `vec![expr; expr]`
             if vec_with_semi {
-                handle_vec_semi(context, shape, arg_vec, macro_name, style)
+                handle_vec_semi(context, shape, arg_vec, callee, style)
             } else {
                 // If we are rewriting `vec!` macro or other special macros,
                 // then we can rewrite this as an usual array literal.
                 // Otherwise, we must preserve the original existence of trailing comma.
-                let macro_name = &macro_name.as_str();
+                let callee = &callee.as_str();
                 let mut force_trailing_comma = if trailing_comma {
                     Some(SeparatorTactic::Always)
                 } else {
                     Some(SeparatorTactic::Never)
                 };
-                if FORCED_BRACKET_MACROS.contains(macro_name) && !is_nested_macro {
+                if is_forced_bracket_macro && !is_nested_macro {
                     context.leave_macro();
                     if context.use_block_indent() {
                         force_trailing_comma = Some(SeparatorTactic::Vertical);
                     };
                 }
                 let rewrite = rewrite_array(
-                    macro_name,
+                    callee,
                     arg_vec.iter(),
                     mac.span(),
                     context,
@@ -505,7 +537,10 @@ pub(crate) fn rewrite_macro_def(
     let mut parser = MacroParser::new(ts.into_trees());
     let parsed_def = match parser.parse() {
         Some(def) => def,
-        None => return snippet,
+        None => {
+            report_unparsed_code(context, span);
+            return snippet;
+        }
     };
 
     let mut result = if def.macro_rules {
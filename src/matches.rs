@@ -2,12 +2,15 @@
 
 use std::iter::repeat;
 
+use rustc_ast::visit::{self, Visitor};
 use rustc_ast::{ast, ptr};
 use rustc_span::{BytePos, Span};
 
 use crate::comment::{combine_strs_with_missing_comments, rewrite_comment};
 use crate::config::lists::*;
-use crate::config::{Config, ControlBraceStyle, IndentStyle, MatchArmLeadingPipe, Version};
+use crate::config::{
+    Config, ControlBraceStyle, FileName, IndentStyle, MatchArmLeadingPipe, Version,
+};
 use crate::expr::{
     format_expr, is_empty_block, is_simple_block, is_unsafe_block, prefer_next_line, rewrite_cond,
     ExprType, RhsTactics,
@@ -17,10 +20,12 @@ use crate::rewrite::{Rewrite, RewriteContext};
 use crate::shape::Shape;
 use crate::source_map::SpanUtils;
 use crate::spanned::Spanned;
+use crate::syntux::session::ParseSess;
 use crate::utils::{
     contains_skip, extra_offset, first_line_width, inner_attributes, last_line_extendable, mk_sp,
     semicolon_for_expr, trimmed_last_line_width, unicode_str_width,
 };
+use crate::{ErrorKind, FormatReport, FormattingError};
 
 /// A simple wrapper type against `ast::Arm`. Used inside `write_list()`.
 struct ArmWrapper<'a> {
@@ -209,7 +214,8 @@ fn rewrite_match_arms(
     // We will add/remove commas inside `arm.rewrite()`, and hence no separator here.
     let fmt = ListFormatting::new(arm_shape, context.config)
         .separator("")
-        .preserve_newline(true);
+        .preserve_newline(true)
+        .max_blank_lines(context.config.blank_lines_between_match_arms());
 
     write_list(&arms_vec, &fmt)
 }
@@ -595,3 +601,64 @@ fn can_flatten_block_around_this(body: &ast::Expr) -> bool {
         _ => false,
     }
 }
+
+/// Finds the match arms which follow a catch-all arm, and so can never be reached.
+#[derive(Default)]
+struct UnreachableArmVisitor {
+    spans: Vec<Span>,
+}
+
+impl<'ast> Visitor<'ast> for UnreachableArmVisitor {
+    fn visit_expr(&mut self, expr: &'ast ast::Expr) {
+        if let ast::ExprKind::Match(_, ref arms) = expr.kind {
+            let catch_all = arms
+                .iter()
+                .position(|arm| arm.guard.is_none() && is_catch_all_pat(&arm.pat));
+            if let Some(i) = catch_all {
+                self.spans
+                    .extend(arms[i + 1..].iter().map(|arm| arm.pat.span));
+            }
+        }
+        visit::walk_expr(self, expr);
+    }
+}
+
+// Whether a pattern matches every value, i.e. it is `_`, a binding or an or-pattern with either.
+fn is_catch_all_pat(pat: &ast::Pat) -> bool {
+    match pat.kind {
+        ast::PatKind::Wild => true,
+        // A lone identifier may also be a unit variant or a constant, which unlike bindings
+        // are capitalized.
+        ast::PatKind::Ident(ast::BindingMode::ByValue(ast::Mutability::Not), ident, None) => {
+            let name = ident.as_str();
+            name.starts_with(|c: char| c.is_lowercase() || c == '_')
+        }
+        ast::PatKind::Ident(_, _, None) => true,
+        ast::PatKind::Paren(ref pat) => is_catch_all_pat(pat),
+        ast::PatKind::Or(ref pats) => pats.iter().any(|pat| is_catch_all_pat(pat)),
+        _ => false,
+    }
+}
+
+/// Reports the match arms in `items` which follow a catch-all arm. The arms are not removed or
+/// moved, since with guards the order of the arms matters.
+pub(crate) fn report_unreachable_arms(
+    items: &[ptr::P<ast::Item>],
+    file_name: &FileName,
+    parse_sess: &ParseSess,
+    report: &FormatReport,
+) {
+    let mut visitor = UnreachableArmVisitor::default();
+    for item in items {
+        visitor.visit_item(item);
+    }
+
+    let errors = visitor
+        .spans
+        .into_iter()
+        .map(|span| FormattingError::from_span(span, parse_sess, ErrorKind::UnreachableMatchArm))
+        .collect::<Vec<_>>();
+    if !errors.is_empty() {
+        report.append(file_name.clone(), errors);
+    }
+}
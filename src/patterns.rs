@@ -4,6 +4,7 @@ use rustc_span::{BytePos, Span};
 
 use crate::comment::{combine_strs_with_missing_comments, FindUncommented};
 use crate::config::lists::*;
+use crate::config::MatchPatternBreak;
 use crate::expr::{can_be_overflowed_expr, rewrite_unary_prefix, wrap_struct_field};
 use crate::lists::{
     definitive_tactic, itemize_list, shape_for_tactic, struct_lit_formatting, struct_lit_shape,
@@ -90,10 +91,19 @@ impl Rewrite for Pat {
                         shape.width,
                     )
                 };
+                let separator_place =
+                    if context.config.was_set().match_pattern_separator_break_point() {
+                        match context.config.match_pattern_separator_break_point() {
+                            MatchPatternBreak::Before => SeparatorPlace::Front,
+                            MatchPatternBreak::After => SeparatorPlace::Back,
+                        }
+                    } else {
+                        context.config.binop_separator()
+                    };
                 let fmt = ListFormatting::new(shape, context.config)
                     .tactic(tactic)
                     .separator(" |")
-                    .separator_place(context.config.binop_separator())
+                    .separator_place(separator_place)
                     .ends_with_newline(false);
                 write_list(&items, &fmt)
             }
@@ -453,21 +463,23 @@ fn rewrite_tuple_pat(
     if pat_vec.is_empty() {
         return Some(format!("{}()", path_str.unwrap_or_default()));
     }
+    // A pattern which already contains `..` can't have another one.
+    let has_rest = pats.iter().any(|p| matches!(p.kind, ast::PatKind::Rest));
     let wildcard_suffix_len = count_wildcard_suffix_len(context, &pat_vec, span, shape);
-    let (pat_vec, span) = if context.config.condense_wildcard_suffixes() && wildcard_suffix_len >= 2
-    {
-        let new_item_count = 1 + pat_vec.len() - wildcard_suffix_len;
-        let sp = pat_vec[new_item_count - 1].span();
-        let snippet = context.snippet(sp);
-        let lo = sp.lo() + BytePos(snippet.find_uncommented("_").unwrap() as u32);
-        pat_vec[new_item_count - 1] = TuplePatField::Dotdot(mk_sp_lo_plus_one(lo));
-        (
-            &pat_vec[..new_item_count],
-            mk_sp(span.lo(), lo + BytePos(1)),
-        )
-    } else {
-        (&pat_vec[..], span)
-    };
+    let (pat_vec, span) =
+        if context.config.condense_wildcard_suffixes() && wildcard_suffix_len >= 2 && !has_rest {
+            let new_item_count = 1 + pat_vec.len() - wildcard_suffix_len;
+            let sp = pat_vec[new_item_count - 1].span();
+            let snippet = context.snippet(sp);
+            let lo = sp.lo() + BytePos(snippet.find_uncommented("_").unwrap() as u32);
+            pat_vec[new_item_count - 1] = TuplePatField::Dotdot(mk_sp_lo_plus_one(lo));
+            (
+                &pat_vec[..new_item_count],
+                mk_sp(span.lo(), lo + BytePos(1)),
+            )
+        } else {
+            (&pat_vec[..], span)
+        };
 
     let is_last_pat_dotdot = pat_vec.last().map_or(false, |p| p.is_dotdot());
     let add_comma = path_str.is_none() && pat_vec.len() == 1 && !is_last_pat_dotdot;
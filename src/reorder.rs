@@ -9,7 +9,7 @@
 use std::cmp::{Ord, Ordering};
 
 use rustc_ast::ast;
-use rustc_span::{symbol::sym, Span};
+use rustc_span::{symbol::sym, BytePos, Span};
 
 use crate::config::{Config, GroupImportsTactic, ImportGranularity};
 use crate::imports::{flatten_use_trees, merge_use_trees, SharedPrefix, UseSegment, UseTree};
@@ -22,6 +22,10 @@ use crate::spanned::Spanned;
 use crate::utils::{contains_skip, mk_sp};
 use crate::visitor::FmtVisitor;
 
+/// Comment markers delimiting a region whose imports are kept in source order.
+const IMPORT_ORDER_FENCE_BEGIN: &str = "// rustfmt::import-order-begin";
+const IMPORT_ORDER_FENCE_END: &str = "// rustfmt::import-order-end";
+
 /// Choose the ordering between the given two items.
 fn compare_items(a: &ast::Item, b: &ast::Item) -> Ordering {
     match (&a.kind, &b.kind) {
@@ -266,10 +270,17 @@ impl<'b, 'a: 'b> FmtVisitor<'a> {
         in_group: bool,
     ) -> usize {
         let mut last = self.parse_sess.lookup_line_range(items[0].span());
+        let first_cfg_attrs = self.cfg_attr_snippets(items[0]);
+        // Modules guarded by different `#[cfg(..)]` attributes are never reordered relative to
+        // each other.
         let item_length = items
             .iter()
-            .take_while(|ppi| {
+            .enumerate()
+            .take_while(|(i, ppi)| {
                 item_kind.is_same_item_kind(&***ppi)
+                    && !self.starts_import_order_fence(items, *i)
+                    && (item_kind != ReorderableItemKind::Mod
+                        || self.cfg_attr_snippets(ppi) == first_cfg_attrs)
                     && (!in_group || {
                         let current = self.parse_sess.lookup_line_range(ppi.span());
                         let in_same_group = current.lo < last.hi + 2;
@@ -304,6 +315,54 @@ impl<'b, 'a: 'b> FmtVisitor<'a> {
         item_length
     }
 
+    /// Returns the `#[cfg(..)]` attributes of `item` as written in the source.
+    fn cfg_attr_snippets(&self, item: &ast::Item) -> Vec<&str> {
+        item.attrs
+            .iter()
+            .filter(|attr| attr.has_name(sym::cfg))
+            .map(|attr| self.snippet(attr.span))
+            .collect()
+    }
+
+    /// Returns `true` if the comments between `lo` and the start of `item` contain
+    /// the given fence marker on a line of its own.
+    fn has_fence_marker(&self, lo: BytePos, item: &ast::Item, marker: &str) -> bool {
+        let hi = item.span().lo();
+        lo < hi
+            && self
+                .snippet(mk_sp(lo, hi))
+                .lines()
+                .any(|line| line.trim() == marker)
+    }
+
+    /// Returns `true` if `items[i]` is the first item of an import order fence.
+    fn starts_import_order_fence(&self, items: &[&ast::Item], i: usize) -> bool {
+        let lo = if i == 0 {
+            self.last_pos
+        } else {
+            items[i - 1].span().hi()
+        };
+        self.has_fence_marker(lo, items[i], IMPORT_ORDER_FENCE_BEGIN)
+    }
+
+    /// Visits the items of an import order fence without reordering them. The fence
+    /// ends at the first item preceded by the end marker, or at the end of `items`.
+    /// Returns the number of visited items.
+    fn visit_import_order_fence(&mut self, items: &[&ast::Item]) -> usize {
+        let mut fence_length = 0;
+        for (i, item) in items.iter().enumerate() {
+            if i > 0 {
+                let prev_hi = items[i - 1].span().hi();
+                if self.has_fence_marker(prev_hi, item, IMPORT_ORDER_FENCE_END) {
+                    break;
+                }
+            }
+            self.visit_item(item);
+            fence_length += 1;
+        }
+        fence_length
+    }
+
     /// Visits and format the given items. Items are reordered If they are
     /// consecutive and reorderable.
     pub(crate) fn visit_items_with_reordering(&mut self, mut items: &[&ast::Item]) {
@@ -312,6 +371,12 @@ impl<'b, 'a: 'b> FmtVisitor<'a> {
             // subsequent items that have the same item kind to be reordered within
             // `walk_reorderable_items`. Otherwise, just format the next item for output.
             let item_kind = ReorderableItemKind::from(items[0]);
+            if self.starts_import_order_fence(items, 0) {
+                let visited_items_num = self.visit_import_order_fence(items);
+                let (_, rest) = items.split_at(visited_items_num);
+                items = rest;
+                continue;
+            }
             if item_kind.is_reorderable(self.config) || item_kind.is_regroupable(self.config) {
                 let visited_items_num = self.walk_reorderable_or_regroupable_items(
                     items,
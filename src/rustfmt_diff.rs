@@ -3,6 +3,9 @@ use std::fmt;
 use std::io;
 use std::io::Write;
 
+use term::terminfo::TerminfoTerminal;
+use term::Terminal;
+
 use crate::config::{Color, Config, Verbosity};
 
 #[derive(Debug, PartialEq)]
@@ -144,37 +147,47 @@ impl std::str::FromStr for ModifiedLines {
 
 // This struct handles writing output to stdout and abstracts away the logic
 // of printing in color, if it's possible in the executing environment.
-pub(crate) struct OutputWriter {
-    terminal: Option<Box<dyn term::Terminal<Output = io::Stdout>>>,
+pub(crate) struct OutputWriter<'a> {
+    output: &'a mut dyn Write,
+    // Renders the escape codes of the colors, which are then copied to `output`.
+    terminal: Option<TerminfoTerminal<Vec<u8>>>,
 }
 
-impl OutputWriter {
+impl<'a> OutputWriter<'a> {
     // Create a new OutputWriter instance based on the caller's preference
     // for colorized output and the capabilities of the terminal.
-    pub(crate) fn new(color: Color) -> Self {
-        if let Some(t) = term::stdout() {
-            if color.use_colored_tty() && t.supports_color() {
-                return OutputWriter { terminal: Some(t) };
+    pub(crate) fn new(output: &'a mut dyn Write, color: Color) -> Self {
+        let terminal = match term::stdout() {
+            Some(t) if color.use_colored_tty() && t.supports_color() => {
+                TerminfoTerminal::new(Vec::new())
             }
-        }
-        OutputWriter { terminal: None }
+            _ => None,
+        };
+        OutputWriter { output, terminal }
     }
 
     // Write output in the optionally specified color. The output is written
     // in the specified color if this OutputWriter instance contains a
     // Terminal in its `terminal` field.
-    pub(crate) fn writeln(&mut self, msg: &str, color: Option<term::color::Color>) {
+    pub(crate) fn writeln(
+        &mut self,
+        msg: &str,
+        color: Option<term::color::Color>,
+    ) -> io::Result<()> {
         match &mut self.terminal {
             Some(ref mut t) => {
                 if let Some(color) = color {
                     t.fg(color).unwrap();
                 }
-                writeln!(t, "{}", msg).unwrap();
+                writeln!(t, "{}", msg)?;
                 if color.is_some() {
                     t.reset().unwrap();
                 }
+                self.output.write_all(t.get_ref())?;
+                t.get_mut().clear();
+                Ok(())
             }
-            None => println!("{}", msg),
+            None => writeln!(self.output, "{}", msg),
         }
     }
 }
@@ -248,7 +261,12 @@ pub(crate) fn make_diff(expected: &str, actual: &str, context_size: usize) -> Ve
     results
 }
 
-pub(crate) fn print_diff<F>(diff: Vec<Mismatch>, get_section_title: F, config: &Config)
+pub(crate) fn print_diff<F>(
+    output: &mut dyn Write,
+    diff: Vec<Mismatch>,
+    get_section_title: F,
+    config: &Config,
+) -> io::Result<()>
 where
     F: Fn(u32) -> String,
 {
@@ -259,34 +277,95 @@ where
         ""
     };
 
-    let mut writer = OutputWriter::new(color);
+    let mut writer = OutputWriter::new(output, color);
 
     for mismatch in diff {
         let title = get_section_title(mismatch.line_number_orig);
-        writer.writeln(&title, None);
+        writer.writeln(&title, None)?;
 
         for line in mismatch.lines {
             match line {
                 DiffLine::Context(ref str) => {
-                    writer.writeln(&format!(" {}{}", str, line_terminator), None)
+                    writer.writeln(&format!(" {}{}", str, line_terminator), None)?
                 }
                 DiffLine::Expected(ref str) => writer.writeln(
                     &format!("+{}{}", str, line_terminator),
                     Some(term::color::GREEN),
-                ),
+                )?,
                 DiffLine::Resulting(ref str) => writer.writeln(
                     &format!("-{}{}", str, line_terminator),
                     Some(term::color::RED),
-                ),
+                )?,
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Prints the diff of a file in the unified format understood by `patch` and `git apply`.
+pub(crate) fn print_unified_diff(
+    output: &mut dyn Write,
+    diff: Vec<Mismatch>,
+    file_name: &str,
+    config: &Config,
+) -> io::Result<()> {
+    let mut writer = OutputWriter::new(output, config.color());
+
+    writer.writeln(&format!("--- {}", file_name), None)?;
+    writer.writeln(&format!("+++ {}", file_name), None)?;
+
+    for mismatch in diff {
+        writer.writeln(&unified_hunk_header(&mismatch), Some(term::color::CYAN))?;
+
+        for line in mismatch.lines {
+            match line {
+                DiffLine::Context(ref str) => writer.writeln(&format!(" {}", str), None)?,
+                DiffLine::Expected(ref str) => {
+                    writer.writeln(&format!("+{}", str), Some(term::color::GREEN))?
+                }
+                DiffLine::Resulting(ref str) => {
+                    writer.writeln(&format!("-{}", str), Some(term::color::RED))?
+                }
             }
         }
     }
+    Ok(())
+}
+
+// Returns the `@@ -l,s +l,s @@` header of a hunk of a unified diff.
+fn unified_hunk_header(mismatch: &Mismatch) -> String {
+    let orig_len = mismatch
+        .lines
+        .iter()
+        .filter(|line| !matches!(line, DiffLine::Expected(_)))
+        .count();
+    let len = mismatch
+        .lines
+        .iter()
+        .filter(|line| !matches!(line, DiffLine::Resulting(_)))
+        .count();
+    // An empty range starts at the line before it.
+    let start = |line_number: u32, len: usize| {
+        if len == 0 {
+            line_number.saturating_sub(1)
+        } else {
+            line_number
+        }
+    };
+
+    format!(
+        "@@ -{},{} +{},{} @@",
+        start(mismatch.line_number_orig, orig_len),
+        orig_len,
+        start(mismatch.line_number, len),
+        len
+    )
 }
 
 #[cfg(test)]
 mod test {
     use super::DiffLine::*;
-    use super::{make_diff, Mismatch};
+    use super::{make_diff, unified_hunk_header, Mismatch};
     use super::{ModifiedChunk, ModifiedLines};
 
     #[test]
@@ -340,6 +419,18 @@ mod test {
         );
     }
 
+    #[test]
+    fn unified_hunk_headers() {
+        let src = "one\ntwo\nthree\nfour\nfive\nsix\nseven\n";
+        let dest = "one\ntwo\ntrois\nfour\nfive\nsix\nsept\nhuit\n";
+        let diff = make_diff(src, dest, 1);
+        assert_eq!(unified_hunk_header(&diff[0]), "@@ -2,3 +2,3 @@");
+        assert_eq!(unified_hunk_header(&diff[1]), "@@ -6,2 +6,3 @@");
+
+        let diff = make_diff("one\ntwo\n", "one\none and a half\ntwo\n", 0);
+        assert_eq!(unified_hunk_header(&diff[0]), "@@ -1,0 +2,1 @@");
+    }
+
     #[test]
     fn diff_zerocontext() {
         let src = "one\ntwo\nthree\nfour\nfive\n";
@@ -52,6 +52,11 @@ impl Indent {
         self
     }
 
+    pub(crate) fn continuation_indent(mut self, config: &Config) -> Indent {
+        self.block_indent += config.continuation_indent_width_or_default();
+        self
+    }
+
     pub(crate) fn block_unindent(mut self, config: &Config) -> Indent {
         if self.block_indent < config.tab_spaces() {
             Indent::new(self.block_indent, 0)
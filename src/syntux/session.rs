@@ -1,8 +1,13 @@
+use std::io::{self, Write};
+use std::mem;
+use std::ops::Deref;
 use std::path::Path;
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
 
 use rustc_data_structures::sync::{Lrc, Send};
-use rustc_errors::emitter::{Emitter, EmitterWriter};
+use rustc_errors::emitter::{Emitter, EmitterWriter, HumanReadableErrorType};
+use rustc_errors::json::JsonEmitter;
 use rustc_errors::{ColorConfig, Diagnostic, Handler, Level as DiagnosticLevel};
 use rustc_session::parse::ParseSess as RawParseSess;
 use rustc_span::{
@@ -11,6 +16,7 @@ use rustc_span::{
 };
 
 use crate::config::file_lines::LineRange;
+use crate::config::ErrorEmission;
 use crate::ignore_path::IgnorePathSet;
 use crate::source_map::LineRangeUtils;
 use crate::utils::starts_with_newline;
@@ -38,6 +44,29 @@ fn silent_emitter() -> Box<dyn Emitter + Send> {
     Box::new(SilentEmitter {})
 }
 
+/// Buffer the diagnostics of the parser are written to instead of stderr, which is shared with
+/// the `Session` so that they can be printed along with the rest of the output of a file.
+#[derive(Clone, Default)]
+pub(crate) struct DiagnosticBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl DiagnosticBuffer {
+    /// Returns the diagnostics written so far, and empties the buffer.
+    pub(crate) fn take(&self) -> Vec<u8> {
+        mem::take(&mut *self.0.lock().unwrap())
+    }
+}
+
+impl Write for DiagnosticBuffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
 /// Emit errors against every files expect ones specified in the `ignore_path_set`.
 struct SilentOnIgnoredFilesEmitter {
     ignore_path_set: Lrc<IgnorePathSet>,
@@ -45,13 +74,16 @@ struct SilentOnIgnoredFilesEmitter {
     emitter: Box<dyn Emitter + Send>,
     has_non_ignorable_parser_errors: bool,
     can_reset: Lrc<AtomicBool>,
+    hide_parse_errors: bool,
 }
 
 impl SilentOnIgnoredFilesEmitter {
     fn handle_non_ignoreable_error(&mut self, db: &Diagnostic) {
         self.has_non_ignorable_parser_errors = true;
         self.can_reset.store(false, Ordering::Release);
-        self.emitter.emit_diagnostic(db);
+        if !self.hide_parse_errors {
+            self.emitter.emit_diagnostic(db);
+        }
     }
 }
 
@@ -79,6 +111,11 @@ impl Emitter for SilentOnIgnoredFilesEmitter {
                 }
             };
         }
+        if db.level == DiagnosticLevel::Warning {
+            // Warnings, such as the reports of TODO and FIXME, are not hidden by
+            // `hide_parse_errors` and don't prevent the parser errors from being reset.
+            return self.emitter.emit_diagnostic(db);
+        }
         self.handle_non_ignoreable_error(db);
     }
 }
@@ -88,6 +125,8 @@ fn default_handler(
     ignore_path_set: Lrc<IgnorePathSet>,
     can_reset: Lrc<AtomicBool>,
     hide_parse_errors: bool,
+    error_emission: ErrorEmission,
+    diagnostics: Option<DiagnosticBuffer>,
 ) -> Handler {
     let supports_color = term::stderr().map_or(false, |term| term.supports_color());
     let color_cfg = if supports_color {
@@ -96,8 +135,32 @@ fn default_handler(
         ColorConfig::Never
     };
 
-    let emitter = if hide_parse_errors {
-        silent_emitter()
+    let emitter: Box<dyn Emitter + Send> = if error_emission == ErrorEmission::Json {
+        let json_rendered = HumanReadableErrorType::Default(ColorConfig::Never);
+        Box::new(match diagnostics {
+            Some(buffer) => JsonEmitter::new(
+                Box::new(buffer),
+                None,
+                source_map.clone(),
+                false,
+                json_rendered,
+                None,
+                false,
+            ),
+            None => {
+                JsonEmitter::stderr(None, source_map.clone(), false, json_rendered, None, false)
+            }
+        })
+    } else if let Some(buffer) = diagnostics {
+        Box::new(EmitterWriter::new(
+            Box::new(buffer),
+            Some(source_map.clone()),
+            false,
+            false,
+            supports_color,
+            None,
+            false,
+        ))
     } else {
         Box::new(EmitterWriter::stderr(
             color_cfg,
@@ -117,12 +180,16 @@ fn default_handler(
             emitter,
             ignore_path_set,
             can_reset,
+            hide_parse_errors,
         }),
     )
 }
 
 impl ParseSess {
-    pub(crate) fn new(config: &Config) -> Result<ParseSess, ErrorKind> {
+    pub(crate) fn new(
+        config: &Config,
+        diagnostics: Option<DiagnosticBuffer>,
+    ) -> Result<ParseSess, ErrorKind> {
         let ignore_path_set = match IgnorePathSet::from_ignore_list(&config.ignore()) {
             Ok(ignore_path_set) => Lrc::new(ignore_path_set),
             Err(e) => return Err(ErrorKind::InvalidGlobPattern(e)),
@@ -135,6 +202,8 @@ impl ParseSess {
             Lrc::clone(&ignore_path_set),
             Lrc::clone(&can_reset_errors),
             config.hide_parse_errors(),
+            config.error_emission(),
+            diagnostics,
         );
         let parse_sess = RawParseSess::with_span_handler(handler, source_map);
 
@@ -167,8 +236,21 @@ impl ParseSess {
         self.ignore_path_set.as_ref().is_match(&path)
     }
 
-    pub(crate) fn set_silent_emitter(&mut self) {
-        self.parse_sess.span_diagnostic = Handler::with_emitter(true, None, silent_emitter());
+    pub(crate) fn emit_diagnostics(&self, diagnostics: Vec<Diagnostic>) {
+        for diagnostic in diagnostics {
+            self.parse_sess.span_diagnostic.emit_diagnostic(&diagnostic);
+        }
+    }
+
+    /// Silences errors until the returned guard is dropped, which restores the previous
+    /// emitter.
+    pub(crate) fn silence_errors(&mut self) -> EmitterGuard<'_> {
+        let silent_handler = Handler::with_emitter(true, None, silent_emitter());
+        let handler = mem::replace(&mut self.parse_sess.span_diagnostic, silent_handler);
+        EmitterGuard {
+            parse_sess: self,
+            handler: Some(handler),
+        }
     }
 
     pub(crate) fn span_to_filename(&self, span: Span) -> FileName {
@@ -218,12 +300,6 @@ impl ParseSess {
 
 // Methods that should be restricted within the syntux module.
 impl ParseSess {
-    pub(super) fn emit_diagnostics(&self, diagnostics: Vec<Diagnostic>) {
-        for diagnostic in diagnostics {
-            self.parse_sess.span_diagnostic.emit_diagnostic(&diagnostic);
-        }
-    }
-
     pub(crate) fn emit_or_cancel_diagnostic(&self, diagnostic: &mut Diagnostic) {
         self.parse_sess.span_diagnostic.emit_diagnostic(diagnostic);
         // The Handler will check whether the diagnostic should be emitted
@@ -240,11 +316,41 @@ impl ParseSess {
     }
 
     pub(super) fn has_errors(&self) -> bool {
-        self.parse_sess.span_diagnostic.has_errors()
+        self.error_count() > 0
+    }
+
+    /// The number of errors emitted since the count was last reset.
+    pub(super) fn error_count(&self) -> usize {
+        self.parse_sess.span_diagnostic.err_count()
     }
 
-    pub(super) fn reset_errors(&self) {
+    /// Resets the error count, returning the number of errors it held.
+    pub(super) fn reset_errors(&self) -> usize {
+        let count = self.error_count();
         self.parse_sess.span_diagnostic.reset_err_count();
+        count
+    }
+}
+
+/// Restores the emitter replaced by `ParseSess::silence_errors` when dropped.
+pub(crate) struct EmitterGuard<'a> {
+    parse_sess: &'a mut ParseSess,
+    handler: Option<Handler>,
+}
+
+impl Deref for EmitterGuard<'_> {
+    type Target = ParseSess;
+
+    fn deref(&self) -> &ParseSess {
+        self.parse_sess
+    }
+}
+
+impl Drop for EmitterGuard<'_> {
+    fn drop(&mut self) {
+        if let Some(handler) = self.handler.take() {
+            self.parse_sess.parse_sess.span_diagnostic = handler;
+        }
     }
 }
 
@@ -332,6 +438,7 @@ mod tests {
                 emitter: Box::new(emitter_writer),
                 ignore_path_set,
                 can_reset,
+                hide_parse_errors: false,
             }
         }
 
@@ -459,5 +566,40 @@ mod tests {
             assert_eq!(num_emitted_errors.load(Ordering::Acquire), 2);
             assert_eq!(can_reset_errors.load(Ordering::Acquire), false);
         }
+
+        #[test]
+        fn counts_and_resets_errors() {
+            let mut parse_sess = ParseSess::new(&Config::default(), None).unwrap();
+            let parse_sess = parse_sess.silence_errors();
+            let diagnostics = vec![
+                build_diagnostic(DiagnosticLevel::Error, None),
+                build_diagnostic(DiagnosticLevel::Error, None),
+                build_diagnostic(DiagnosticLevel::Warning, None),
+            ];
+            parse_sess.emit_diagnostics(diagnostics);
+            assert_eq!(parse_sess.error_count(), 2);
+            assert_eq!(parse_sess.reset_errors(), 2);
+            assert_eq!(parse_sess.error_count(), 0);
+            assert!(!parse_sess.has_errors());
+        }
+
+        #[test]
+        fn reports_errors_again_after_silencing() {
+            let diagnostics = DiagnosticBuffer::default();
+            let mut parse_sess =
+                ParseSess::new(&Config::default(), Some(diagnostics.clone())).unwrap();
+            {
+                let silenced = parse_sess.silence_errors();
+                silenced.emit_diagnostics(vec![build_diagnostic(DiagnosticLevel::Error, None)]);
+                assert!(silenced.has_errors());
+            }
+            assert!(diagnostics.take().is_empty());
+            // The errors were counted by the silent handler, which has been dropped.
+            assert!(!parse_sess.has_errors());
+
+            parse_sess.emit_diagnostics(vec![build_diagnostic(DiagnosticLevel::Error, None)]);
+            assert!(parse_sess.has_errors());
+            assert!(!diagnostics.take().is_empty());
+        }
     }
 }
@@ -9,6 +9,7 @@ use std::process::{Command, Stdio};
 use std::str::Chars;
 use std::thread;
 
+use crate::config::file_directives;
 use crate::config::{Color, Config, EmitMode, FileName, NewlineStyle, ReportTactic};
 use crate::formatting::{ReportedErrors, SourceFile};
 use crate::rustfmt_diff::{make_diff, print_diff, DiffLine, Mismatch, ModifiedChunk, OutputWriter};
@@ -25,6 +26,8 @@ const SKIP_FILE_WHITE_LIST: &[&str] = &[
     // We want to make sure that the `skip_children` is correctly working,
     // so we do not want to test this file directly.
     "configs/skip_children/foo/mod.rs",
+    "configs/skip_children/bar/baz.rs",
+    "configs/skip_children/included.rs",
     "issue-3434/no_entry.rs",
     "issue-3665/sub_mod.rs",
     // Testing for issue-3779
@@ -160,13 +163,13 @@ fn verify_config_test_names() {
     }
 }
 
-// This writes to the terminal using the same approach (via `term::stdout` or
-// `println!`) that is used by `rustfmt::rustfmt_diff::print_diff`. Writing
-// using only one or the other will cause the output order to differ when
-// `print_diff` selects the approach not used.
+// This writes to stdout using the same approach that is used by
+// `rustfmt::rustfmt_diff::print_diff`, so that the messages and the diffs
+// are printed in order.
 fn write_message(msg: &str) {
-    let mut writer = OutputWriter::new(Color::Auto);
-    writer.writeln(msg, None);
+    let stdout = &mut io::stdout();
+    let mut writer = OutputWriter::new(stdout, Color::Auto);
+    writer.writeln(msg, None).unwrap();
 }
 
 // Integration tests. The files in `tests/source` are formatted and compared
@@ -506,6 +509,220 @@ fn format_lines_errors_are_reported() {
     assert!(session.has_formatting_errors());
 }
 
+#[test]
+fn format_lines_comment_overflow_is_reported() {
+    init_log();
+    let long_comment = String::from_utf8(vec![b'a'; 100]).unwrap();
+    let input = format!("fn a() {{}} // {}\n", long_comment);
+    let mut config = Config::default();
+    config.set().error_on_line_overflow(true);
+    let mut session = Session::<io::Stdout>::new(config.clone(), None);
+    session.format(Input::Text(input.clone())).unwrap();
+    assert!(session.has_formatting_errors());
+
+    config.set().error_on_line_overflow_comments(false);
+    let mut session = Session::<io::Stdout>::new(config.clone(), None);
+    session.format(Input::Text(input.clone())).unwrap();
+    assert!(!session.has_formatting_errors());
+
+    // `error_on_unformatted` doesn't override `error_on_line_overflow_comments`.
+    config.set().error_on_unformatted(true);
+    let mut session = Session::<io::Stdout>::new(config, None);
+    session.format(Input::Text(input)).unwrap();
+    assert!(!session.has_formatting_errors());
+}
+
+#[test]
+fn missing_license_is_inserted() {
+    init_log();
+    let mut config = Config::default();
+    config.override_value(
+        "license_template_path",
+        "tests/license-template/insert.txt",
+    );
+    config.set().insert_license(true);
+    config.set().newline_style(NewlineStyle::Unix);
+    config.set().emit_mode(EmitMode::Stdout);
+    let mut buf: Vec<u8> = vec![];
+    {
+        let mut session = Session::new(config.clone(), Some(&mut buf));
+        session
+            .format(Input::Text("fn main () {}".to_owned()))
+            .unwrap();
+        assert!(session.has_no_errors());
+    }
+    assert_eq!(
+        buf,
+        "stdin:\n\n// Licensed under the MIT license.\n\nfn main() {}\n".as_bytes()
+    );
+
+    // A template without a trailing newline is still separated from the code.
+    config.override_value(
+        "license_template_path",
+        "tests/license-template/insert_no_newline.txt",
+    );
+    let mut buf: Vec<u8> = vec![];
+    {
+        let mut session = Session::new(config.clone(), Some(&mut buf));
+        session
+            .format(Input::Text("fn main () {}".to_owned()))
+            .unwrap();
+        assert!(session.has_no_errors());
+    }
+    assert_eq!(
+        buf,
+        "stdin:\n\n// Licensed under the Apache license.\n\nfn main() {}\n".as_bytes()
+    );
+
+    // A header which doesn't match the template is reported rather than replaced.
+    let mut session = Session::<io::Stdout>::new(config, None);
+    let input = "// Copyright the rustfmt developers.\n\nfn main() {}\n";
+    session.format(Input::Text(input.to_owned())).unwrap();
+    assert!(session.has_check_errors());
+}
+
+#[test]
+fn missing_license_is_reported_when_checking() {
+    init_log();
+    let mut config = Config::default();
+    config.override_value(
+        "license_template_path",
+        "tests/license-template/insert.txt",
+    );
+    config.set().insert_license(true);
+    config.set().emit_mode(EmitMode::Diff);
+    let mut buf: Vec<u8> = vec![];
+    let mut session = Session::new(config, Some(&mut buf));
+    session.format(Input::Text("fn main() {}\n".to_owned())).unwrap();
+    assert!(session.has_check_errors());
+    assert!(session.has_diff());
+}
+
+#[test]
+fn ignored_files_are_recorded() {
+    init_log();
+    if !is_nightly_channel!() {
+        return;
+    }
+    let mut config = Config::from_toml(r#"ignore = ["other.rs"]"#, Path::new("")).unwrap();
+    config.set().emit_mode(EmitMode::Stdout);
+    let mut session = Session::<io::Stdout>::new(config, None);
+    let report = session
+        .format(Input::File(PathBuf::from("tests/source/multiple.rs")))
+        .unwrap();
+    let ignored = [FileName::Real(PathBuf::from("tests/source/other.rs"))];
+    assert_eq!(report.ignored_files(), &ignored);
+    assert_eq!(session.ignored_files(), &ignored);
+}
+
+#[test]
+fn unparsed_macro_call_is_reported() {
+    init_log();
+    let input = Input::Text("fn main() {\n    foo!(a b);\n}\n".to_owned());
+    let mut config = Config::default();
+    config.set().error_on_unformatted(true);
+    let mut session = Session::<io::Stdout>::new(config.clone(), None);
+    session.format(input).unwrap();
+    assert!(session.has_unformatted_code_errors());
+
+    // The chain is rewritten several times while looking for a layout which fits, but the macro
+    // call is reported once.
+    let input = Input::Text(
+        "fn main() {\n    let x = receiver.method_one(foo!(a b)).method_two(argument_two)\
+         .method_three(argument_three);\n}\n"
+            .to_owned(),
+    );
+    let mut session = Session::<io::Stdout>::new(config, None);
+    let report = session.format(input).unwrap();
+    assert_eq!(report.warning_count(), 1);
+}
+
+#[test]
+fn unsafe_without_safety_comment_is_reported() {
+    init_log();
+    let input = Input::Text("fn main() {\n    unsafe { foo() }\n}\n".to_owned());
+    let mut config = Config::default();
+    config
+        .set()
+        .report_unsafe_without_safety_comment(ReportTactic::Always);
+    let mut session = Session::<io::Stdout>::new(config.clone(), None);
+    session.format(input).unwrap();
+    assert!(session.has_check_errors());
+
+    let input = "fn main() {\n    // SAFETY: fine\n    unsafe { foo() }\n}\n";
+    let input = Input::Text(input.to_owned());
+    let mut session = Session::<io::Stdout>::new(config, None);
+    session.format(input).unwrap();
+    assert!(!session.has_check_errors());
+}
+
+#[test]
+fn unreachable_match_arms_are_reported() {
+    init_log();
+    let input = "fn main() {\n    match x {\n        _ => {}\n        1 => {}\n    }\n}\n";
+    let mut config = Config::default();
+    config
+        .set()
+        .report_unreachable_match_arms(ReportTactic::Always);
+    let mut session = Session::<io::Stdout>::new(config.clone(), None);
+    session.format(Input::Text(input.to_owned())).unwrap();
+    assert!(session.has_check_errors());
+
+    // Guarded arms and unit variants do not catch everything.
+    let input = "fn main() {\n    match x {\n        y if y > 0 => {}\n        None => {}\n        \
+                 _ => {}\n    }\n}\n";
+    let mut session = Session::<io::Stdout>::new(config, None);
+    session.format(Input::Text(input.to_owned())).unwrap();
+    assert!(!session.has_check_errors());
+}
+
+#[test]
+fn bad_issues_are_reported() {
+    init_log();
+    let mut config = Config::default();
+    config.set().report_todo(ReportTactic::Unnumbered);
+    let input = Input::Text("// TODO(#1)\nfn main() {}\n".to_owned());
+    let mut session = Session::<io::Stdout>::new(config.clone(), None);
+    session.format(input).unwrap();
+    assert!(!session.has_check_errors());
+
+    let input = Input::Text("// TODO: no number\nfn main() {}\n".to_owned());
+    let mut session = Session::<io::Stdout>::new(config.clone(), None);
+    session.format(input).unwrap();
+    assert!(session.has_check_errors());
+
+    // Hiding the parse errors doesn't hide the reports.
+    config.set().hide_parse_errors(true);
+    let input = Input::Text("// TODO: no number\nfn main() {}\n".to_owned());
+    let mut session = Session::<io::Stdout>::new(config, None);
+    session.capture_diagnostics();
+    session.format(input).unwrap();
+    assert!(session.has_check_errors());
+    let diagnostics = String::from_utf8(session.take_diagnostics()).unwrap();
+    assert!(diagnostics.contains("found TODO"));
+}
+
+#[test]
+fn unknown_file_directive_is_an_error() {
+    init_log();
+    let input = Input::Text("// rustfmt-no_such_option: true\nfn main() {}\n".to_owned());
+    let mut session = Session::<io::Stdout>::new(Config::default(), None);
+    assert!(session.format(input).is_err());
+}
+
+#[test]
+fn generated_files_are_skipped() {
+    init_log();
+    let path = "tests/source/configs/format_generated_files/false.rs";
+    let mut config = Config::default();
+    config.set().format_generated_files(false);
+    let (_, source_file, _) = format_file(path, config);
+    assert!(source_file.is_empty());
+
+    let (_, source_file, _) = format_file(path, Config::default());
+    assert_eq!(source_file.len(), 1);
+}
+
 #[test]
 fn format_lines_errors_are_reported_with_tabs() {
     init_log();
@@ -563,7 +780,9 @@ fn print_mismatches_default_message(result: HashMap<PathBuf, Vec<Mismatch>>) {
     for (file_name, diff) in result {
         let mismatch_msg_formatter =
             |line_num| format!("\nMismatch at {}:{}:", file_name.display(), line_num);
-        print_diff(diff, &mismatch_msg_formatter, &Default::default());
+        let stdout = &mut io::stdout();
+        print_diff(stdout, diff, &mismatch_msg_formatter, &Default::default())
+            .unwrap();
     }
 
     if let Some(mut t) = term::stdout() {
@@ -576,7 +795,9 @@ fn print_mismatches<T: Fn(u32) -> String>(
     mismatch_msg_formatter: T,
 ) {
     for (_file_name, diff) in result {
-        print_diff(diff, &mismatch_msg_formatter, &Default::default());
+        let stdout = &mut io::stdout();
+        print_diff(stdout, diff, &mismatch_msg_formatter, &Default::default())
+            .unwrap();
     }
 
     if let Some(mut t) = term::stdout() {
@@ -680,36 +901,11 @@ fn get_config(config_file: Option<&Path>) -> Config {
 
 // Reads significant comments of the form: `// rustfmt-key: value` into a hash map.
 fn read_significant_comments(file_name: &Path) -> HashMap<String, String> {
-    let file = fs::File::open(file_name)
+    let src = fs::read_to_string(file_name)
         .unwrap_or_else(|_| panic!("couldn't read file {}", file_name.display()));
-    let reader = BufReader::new(file);
-    let pattern = r"^\s*//\s*rustfmt-([^:]+):\s*(\S+)";
-    let regex = regex::Regex::new(pattern).expect("failed creating pattern 1");
-
-    // Matches lines containing significant comments or whitespace.
-    let line_regex = regex::Regex::new(r"(^\s*$)|(^\s*//\s*rustfmt-[^:]+:\s*\S+)")
-        .expect("failed creating pattern 2");
-
-    reader
-        .lines()
-        .map(|line| line.expect("failed getting line"))
-        .take_while(|line| line_regex.is_match(line))
-        .filter_map(|line| {
-            regex.captures_iter(&line).next().map(|capture| {
-                (
-                    capture
-                        .get(1)
-                        .expect("couldn't unwrap capture")
-                        .as_str()
-                        .to_owned(),
-                    capture
-                        .get(2)
-                        .expect("couldn't unwrap capture")
-                        .as_str()
-                        .to_owned(),
-                )
-            })
-        })
+    file_directives(&src)
+        .into_iter()
+        .map(|(key, val)| (key.to_owned(), val.to_owned()))
         .collect()
 }
 
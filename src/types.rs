@@ -457,7 +457,7 @@ impl Rewrite for ast::WherePredicate {
                     format!("{}{}", type_str, colon)
                 };
 
-                rewrite_assign_rhs(context, lhs, bounds, shape)?
+                rewrite_assign_rhs(context, lhs, &WhereBounds(bounds), shape)?
             }
             ast::WherePredicate::RegionPredicate(ast::WhereRegionPredicate {
                 ref lifetime,
@@ -558,6 +558,41 @@ impl Rewrite for ast::GenericBounds {
     }
 }
 
+// The bounds of a where-clause predicate, laid out according to `where_bound_layout`.
+struct WhereBounds<'a>(&'a ast::GenericBounds);
+
+impl<'a> Rewrite for WhereBounds<'a> {
+    fn rewrite(&self, context: &RewriteContext<'_>, shape: Shape) -> Option<String> {
+        if self.0.is_empty() {
+            return Some(String::new());
+        }
+
+        let layout = context.config.where_bound_layout();
+        join_bounds_inner(
+            context,
+            shape,
+            self.0,
+            true,
+            layout.is_vertical() && self.0.len() > 1,
+            layout.separator_place(),
+        )
+    }
+}
+
+// The supertraits of a trait, laid out according to `trait_bound_layout`.
+pub(crate) struct SupertraitBounds<'a>(pub(crate) &'a ast::GenericBounds);
+
+impl<'a> Rewrite for SupertraitBounds<'a> {
+    fn rewrite(&self, context: &RewriteContext<'_>, shape: Shape) -> Option<String> {
+        if self.0.is_empty() {
+            return Some(String::new());
+        }
+
+        let separator_place = context.config.trait_bound_layout();
+        join_bounds_inner(context, shape, self.0, true, false, separator_place)
+    }
+}
+
 impl Rewrite for ast::GenericParam {
     fn rewrite(&self, context: &RewriteContext<'_>, shape: Shape) -> Option<String> {
         let mut result = String::with_capacity(128);
@@ -900,7 +935,14 @@ fn join_bounds(
     items: &[ast::GenericBound],
     need_indent: bool,
 ) -> Option<String> {
-    join_bounds_inner(context, shape, items, need_indent, false)
+    join_bounds_inner(
+        context,
+        shape,
+        items,
+        need_indent,
+        false,
+        SeparatorPlace::Front,
+    )
 }
 
 fn join_bounds_inner(
@@ -909,6 +951,7 @@ fn join_bounds_inner(
     items: &[ast::GenericBound],
     need_indent: bool,
     force_newline: bool,
+    separator_place: SeparatorPlace,
 ) -> Option<String> {
     debug_assert!(!items.is_empty());
 
@@ -966,6 +1009,7 @@ fn join_bounds_inner(
 
             let joiner = match context.config.type_punctuation_density() {
                 TypeDensity::Compressed => String::from("+"),
+                TypeDensity::Wide if separator_place.is_back() => String::from(" +") + &whitespace,
                 TypeDensity::Wide => whitespace + "+ ",
             };
             let joiner = if has_leading_comment {
@@ -1020,7 +1064,7 @@ fn join_bounds_inner(
         && items.len() > 1
         && (result.0.contains('\n') || result.0.len() > shape.width)
     {
-        join_bounds_inner(context, shape, items, need_indent, true)
+        join_bounds_inner(context, shape, items, need_indent, true, separator_place)
     } else {
         Some(result.0)
     }
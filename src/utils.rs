@@ -9,7 +9,9 @@ use rustc_ast_pretty::pprust;
 use rustc_span::{sym, symbol, BytePos, ExpnId, Span, Symbol, SyntaxContext};
 use unicode_width::UnicodeWidthStr;
 
-use crate::comment::{filter_normal_code, CharClasses, FullCodeCharKind, LineClasses};
+use crate::comment::{
+    contains_comment, filter_normal_code, CharClasses, FullCodeCharKind, LineClasses,
+};
 use crate::config::{Config, Version};
 use crate::rewrite::RewriteContext;
 use crate::shape::{Indent, Shape};
@@ -312,6 +314,11 @@ pub(crate) fn semicolon_for_stmt(context: &RewriteContext<'_>, stmt: &ast::Stmt)
             ast::ExprKind::Break(..) | ast::ExprKind::Continue(..) | ast::ExprKind::Ret(..) => {
                 context.config.trailing_semicolon()
             }
+            ast::ExprKind::Block(..) | ast::ExprKind::If(..) | ast::ExprKind::Match(..)
+                if is_unit_expr(expr) =>
+            {
+                context.config.trailing_semicolon()
+            }
             _ => true,
         },
         ast::StmtKind::Expr(..) => false,
@@ -319,6 +326,35 @@ pub(crate) fn semicolon_for_stmt(context: &RewriteContext<'_>, stmt: &ast::Stmt)
     }
 }
 
+/// Returns `true` if `expr` syntactically evaluates to `()`, so that the semicolon following it
+/// in statement position has no effect. This is conservative: anything whose type cannot be
+/// determined without type checking (e.g., a macro call or a `loop` with a `break` value) is
+/// treated as non-unit.
+fn is_unit_expr(expr: &ast::Expr) -> bool {
+    match expr.kind {
+        ast::ExprKind::Tup(ref exprs) => exprs.is_empty(),
+        ast::ExprKind::While(..) | ast::ExprKind::ForLoop(..) => true,
+        ast::ExprKind::Block(ref block, _) => is_unit_block(block),
+        ast::ExprKind::If(_, ref block, ref else_expr) => {
+            is_unit_block(block) && else_expr.as_ref().map_or(true, |e| is_unit_expr(e))
+        }
+        ast::ExprKind::Match(_, ref arms) => arms.iter().all(|arm| is_unit_expr(&arm.body)),
+        ast::ExprKind::Paren(ref expr) => is_unit_expr(expr),
+        _ => false,
+    }
+}
+
+fn is_unit_block(block: &ast::Block) -> bool {
+    match block.stmts.last() {
+        Some(stmt) => match stmt.kind {
+            ast::StmtKind::Expr(ref expr) => is_unit_expr(expr),
+            ast::StmtKind::MacCall(..) => false,
+            _ => true,
+        },
+        None => true,
+    }
+}
+
 #[inline]
 pub(crate) fn stmt_expr(stmt: &ast::Stmt) -> Option<&ast::Expr> {
     match stmt.kind {
@@ -327,6 +363,75 @@ pub(crate) fn stmt_expr(stmt: &ast::Stmt) -> Option<&ast::Expr> {
     }
 }
 
+/// Returns `block` with its last statement `return expr;` replaced by the tail expression `expr`
+/// if `strip_tail_return` is set. `block` must be the body of a function or a closure, so that
+/// the `return` is in tail position. `return;`, a `return` followed by a comment and a `return`
+/// whose value may hold temporaries are left alone.
+pub(crate) fn strip_tail_return(
+    context: &RewriteContext<'_>,
+    block: &ast::Block,
+) -> Option<ast::Block> {
+    if !context.config.strip_tail_return() {
+        return None;
+    }
+
+    let stmt = block.stmts.last()?;
+    let expr = match stmt.kind {
+        ast::StmtKind::Expr(ref expr) | ast::StmtKind::Semi(ref expr) => expr,
+        _ => return None,
+    };
+    let value = match expr.kind {
+        ast::ExprKind::Ret(Some(ref value)) if expr.attrs.is_empty() => value,
+        _ => return None,
+    };
+    if !is_temporary_free_expr(value)
+        || contains_comment(context.snippet(mk_sp(stmt.span.lo(), value.span.lo())))
+    {
+        return None;
+    }
+
+    let mut block = block.clone();
+    *block.stmts.last_mut()? = ast::Stmt {
+        id: stmt.id,
+        kind: ast::StmtKind::Expr(value.clone()),
+        span: stmt.span,
+    };
+    Some(block)
+}
+
+// Whether `expr` creates no temporaries, which in a tail expression would only be dropped after
+// the locals of the block, and so couldn't borrow them. E.g., `c.borrow().len()` is rejected.
+fn is_temporary_free_expr(expr: &ast::Expr) -> bool {
+    match expr.kind {
+        ast::ExprKind::Lit(..) | ast::ExprKind::Path(..) => true,
+        ast::ExprKind::Paren(ref expr)
+        | ast::ExprKind::Field(ref expr, _)
+        | ast::ExprKind::Cast(ref expr, _)
+        | ast::ExprKind::Unary(ast::UnOp::Neg, ref expr)
+        | ast::ExprKind::Unary(ast::UnOp::Not, ref expr) => is_temporary_free_expr(expr),
+        ast::ExprKind::Binary(_, ref lhs, ref rhs) => {
+            is_temporary_free_expr(lhs) && is_temporary_free_expr(rhs)
+        }
+        ast::ExprKind::Call(ref callee, ref args) => {
+            matches!(callee.kind, ast::ExprKind::Path(..))
+                && args.iter().all(|arg| is_temporary_free_expr(arg))
+        }
+        ast::ExprKind::Tup(ref exprs) | ast::ExprKind::Array(ref exprs) => {
+            exprs.iter().all(|expr| is_temporary_free_expr(expr))
+        }
+        ast::ExprKind::Struct(ref struct_expr) => {
+            let ast::StructExpr { fields, rest, .. } = &**struct_expr;
+            fields.iter().all(|field| is_temporary_free_expr(&field.expr))
+                && match rest {
+                    ast::StructRest::Base(base) => is_temporary_free_expr(base),
+                    ast::StructRest::Rest(_) | ast::StructRest::None => true,
+                }
+        }
+        // Method calls, macros, blocks and so on.
+        _ => false,
+    }
+}
+
 /// Returns the number of LF and CRLF respectively.
 pub(crate) fn count_lf_crlf(input: &str) -> (usize, usize) {
     let mut lf = 0;
@@ -8,6 +8,7 @@ use rustc_span::{BytePos, Span};
 
 use crate::comment::combine_strs_with_missing_comments;
 use crate::config::lists::*;
+use crate::config::Version;
 use crate::expr::rewrite_field;
 use crate::items::{rewrite_struct_field, rewrite_struct_field_prefix};
 use crate::lists::{
@@ -23,6 +24,7 @@ use crate::utils::{
 
 pub(crate) trait AlignedItem {
     fn skip(&self) -> bool;
+    fn has_attrs(&self) -> bool;
     fn get_span(&self) -> Span;
     fn rewrite_prefix(&self, context: &RewriteContext<'_>, shape: Shape) -> Option<String>;
     fn rewrite_aligned_item(
@@ -38,6 +40,10 @@ impl AlignedItem for ast::FieldDef {
         contains_skip(&self.attrs)
     }
 
+    fn has_attrs(&self) -> bool {
+        !self.attrs.is_empty()
+    }
+
     fn get_span(&self) -> Span {
         self.span()
     }
@@ -77,6 +83,10 @@ impl AlignedItem for ast::ExprField {
         contains_skip(&self.attrs)
     }
 
+    fn has_attrs(&self) -> bool {
+        !self.attrs.is_empty()
+    }
+
     fn get_span(&self) -> Span {
         self.span()
     }
@@ -255,7 +265,7 @@ fn rewrite_aligned_items_inner<T: AlignedItem>(
 
 /// Returns the index in `fields` up to which a field belongs to the current group.
 /// The returned string is the group separator to use when rewriting the fields.
-/// Groups are defined by blank lines.
+/// Groups are defined by blank lines and, with version Two, by fields with attributes.
 fn group_aligned_items<T: AlignedItem>(
     context: &RewriteContext<'_>,
     fields: &[T],
@@ -279,6 +289,10 @@ fn group_aligned_items<T: AlignedItem>(
         if has_blank_line {
             return ("\n", index);
         }
+        // A field with attributes or doc comments starts a new group.
+        if context.config.version() == Version::Two && fields[i + 1].has_attrs() {
+            return ("", index);
+        }
         index += 1;
     }
     ("", index)
@@ -26,7 +26,8 @@ use crate::stmt::Stmt;
 use crate::syntux::session::ParseSess;
 use crate::utils::{
     self, contains_skip, count_newlines, depr_skip_annotation, format_unsafety, inner_attributes,
-    last_line_width, mk_sp, ptr_vec_to_ref_vec, rewrite_ident, starts_with_newline, stmt_expr,
+    last_line_width, mk_sp, outer_attributes, ptr_vec_to_ref_vec, rewrite_ident,
+    starts_with_newline, stmt_expr, strip_tail_return,
 };
 use crate::{ErrorKind, FormatReport, FormattingError};
 
@@ -79,6 +80,8 @@ pub(crate) struct FmtVisitor<'a> {
     pub(crate) block_indent: Indent,
     pub(crate) config: &'a Config,
     pub(crate) is_if_else_block: bool,
+    // Whether the next block to be visited is the body of a function.
+    is_fn_body: bool,
     pub(crate) snippet_provider: &'a SnippetProvider,
     pub(crate) line_number: usize,
     /// List of 1-based line ranges which were annotated with skip
@@ -223,6 +226,13 @@ impl<'b, 'a: 'b> FmtVisitor<'a> {
             self.parse_sess.span_to_debug_info(b.span),
         );
 
+        let stripped_block = if std::mem::replace(&mut self.is_fn_body, false) {
+            strip_tail_return(&self.get_context(), b)
+        } else {
+            None
+        };
+        let b = stripped_block.as_ref().unwrap_or(b);
+
         // Check if this block has braces.
         let brace_compensation = BytePos(if has_braces { 1 } else { 0 });
 
@@ -272,6 +282,8 @@ impl<'b, 'a: 'b> FmtVisitor<'a> {
         };
 
         let comment_snippet = self.snippet(span);
+        // Nothing has been written since the opening brace, i.e., the block only has comments.
+        let opens_block = self.buffer.ends_with('{');
 
         let align_to_right = if unindent_comment && contains_comment(&comment_snippet) {
             let first_lines = comment_snippet.splitn(2, '/').next().unwrap_or("");
@@ -337,10 +349,17 @@ impl<'b, 'a: 'b> FmtVisitor<'a> {
                             }
                         };
 
+                        // Don't keep blank lines between the opening brace and the first
+                        // comment of a block which only has comments.
+                        let trim_blank_lines = opens_block
+                            && last_hi == span.lo()
+                            && self.config.version() == Version::Two;
                         if comment_on_same_line {
                             self.push_str(" ");
                         } else {
-                            if count_newlines(snippet_in_between) >= 2 || extra_newline {
+                            if (count_newlines(snippet_in_between) >= 2 || extra_newline)
+                                && !trim_blank_lines
+                            {
                                 self.push_str("\n");
                             }
                             self.push_str(&self.block_indent.to_string_with_newline(config));
@@ -426,6 +445,7 @@ impl<'b, 'a: 'b> FmtVisitor<'a> {
             self.format_missing(source!(self, block.span).lo());
         }
 
+        self.is_fn_body = true;
         self.visit_block(block, inner_attrs, true)
     }
 
@@ -451,6 +471,16 @@ impl<'b, 'a: 'b> FmtVisitor<'a> {
                     true
                 }
             }
+            // Module is inline and its contents are skipped with `#![rustfmt::skip]`.
+            // Only its outer attributes, signature and braces are formatted.
+            ast::ItemKind::Mod(..)
+                if !is_mod_decl(item)
+                    && contains_skip(&item.attrs)
+                    && !contains_skip(&outer_attributes(&item.attrs)) =>
+            {
+                self.visit_attrs(&outer_attributes(&item.attrs), ast::AttrStyle::Outer);
+                true
+            }
             // Module is inline, in this case we treat it like any other item.
             _ if !is_mod_decl(item) => {
                 if self.visit_attrs(&item.attrs, ast::AttrStyle::Outer) {
@@ -848,6 +878,7 @@ impl<'b, 'a: 'b> FmtVisitor<'a> {
             block_indent: Indent::empty(),
             config,
             is_if_else_block: false,
+            is_fn_body: false,
             snippet_provider,
             line_number: 0,
             skipped_range: Rc::new(RefCell::new(vec![])),
@@ -1004,6 +1035,22 @@ impl<'b, 'a: 'b> FmtVisitor<'a> {
                 self.snippet(mk_sp(mod_lo, source!(self, inner_span).hi() - BytePos(1)));
             let body_snippet = body_snippet.trim();
             if body_snippet.is_empty() {
+                if !self.config.empty_item_single_line() {
+                    let indent_str = self.block_indent.to_string_with_newline(self.config);
+                    self.push_str(&indent_str);
+                }
+                self.push_str("}");
+            } else if contains_skip(&inner_attributes(attrs)) {
+                // Keep the contents of the module as is, up to the closing brace.
+                let body_hi = source!(self, inner_span).hi() - BytePos(1);
+                let body = self.snippet(mk_sp(mod_lo, body_hi)).trim_end();
+                let lo = self.line_number + 1;
+                self.push_str(body);
+                self.skipped_range
+                    .borrow_mut()
+                    .push((lo, self.line_number + 1));
+                let indent_str = self.block_indent.to_string_with_newline(self.config);
+                self.push_str(&indent_str);
                 self.push_str("}");
             } else {
                 self.last_pos = mod_lo;
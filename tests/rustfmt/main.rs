@@ -1,7 +1,7 @@
 //! Integration tests for rustfmt.
 
 use std::env;
-use std::fs::remove_file;
+use std::fs::{remove_file, write};
 use std::path::Path;
 use std::process::Command;
 
@@ -76,6 +76,140 @@ fn print_config() {
     remove_file("minimal-config").unwrap();
 }
 
+#[ignore]
+#[test]
+fn print_config_current() {
+    assert_that!(&["--print-config", "current"], contains("PATH required"));
+    assert_that!(
+        &["--print-config", "current", "src/shape.rs"],
+        contains("max_width = 100")
+    );
+    assert_that!(
+        &[
+            "--print-config",
+            "current",
+            "--config",
+            "max_width=80",
+            "src/shape.rs"
+        ],
+        contains("max_width = 80")
+    );
+    assert_that!(
+        &[
+            "--print-config",
+            "diff",
+            "--config",
+            "tab_spaces=2",
+            "src/shape.rs"
+        ],
+        contains("tab_spaces = 2")
+    );
+}
+
+#[ignore]
+#[test]
+fn files_with_diff() {
+    let (stdout, stderr) = rustfmt(&[
+        "--emit",
+        "files-with-diff",
+        "--null",
+        "tests/source/enum.rs",
+        "src/shape.rs",
+    ]);
+    assert_eq!(stdout, "tests/source/enum.rs\0", "stderr:\n{}", stderr);
+
+    let (stdout, stderr) = rustfmt(&["--check", "-l", "tests/source/enum.rs", "src/shape.rs"]);
+    assert_eq!(stdout, "tests/source/enum.rs\n", "stderr:\n{}", stderr);
+    assert_that!(
+        &["--check", "-l", "--null", "src/shape.rs"],
+        contains("can only be used with `--emit files-with-diff`")
+    );
+}
+
+#[ignore]
+#[test]
+fn check_with_verbose_diff() {
+    let (stdout, stderr) = rustfmt(&[
+        "--check",
+        "--verbose-diff",
+        "--config=color=Never",
+        "tests/source/enum.rs",
+    ]);
+    assert!(
+        stdout.starts_with("--- tests/source/enum.rs\n+++ tests/source/enum.rs\n@@ -"),
+        "stdout:\n{}\nstderr:\n{}",
+        stdout,
+        stderr
+    );
+    assert!(!stdout.contains("Diff in "), "stdout:\n{}", stdout);
+}
+
+#[ignore]
+#[test]
+fn files_from() {
+    write(
+        "files-from-manifest",
+        "tests/source/enum.rs\nsrc/shape.rs\ntests/source/missing.rs\n",
+    )
+    .unwrap();
+    assert_that!(
+        &["--check", "-l", "--files-from", "files-from-manifest"],
+        contains("enum.rs") && contains("skipping `tests/source/missing.rs`")
+    );
+    remove_file("files-from-manifest").unwrap();
+}
+
+#[ignore]
+#[test]
+fn check_with_jobs_keeps_input_order() {
+    let files = [
+        "tests/source/enum.rs",
+        "tests/source/expr.rs",
+        "tests/source/impls.rs",
+        "tests/source/structs.rs",
+    ];
+    let mut args = vec!["--check", "--jobs", "4", "--config=color=Never"];
+    args.extend_from_slice(&files);
+    let (stdout, stderr) = rustfmt(&args);
+
+    // The diff of each file is printed in one piece, in the order the files were given.
+    let mut diffed_files = stdout
+        .lines()
+        .filter_map(|line| line.strip_prefix("Diff in "))
+        .filter_map(|line| line.split(" at line ").next())
+        .collect::<Vec<_>>();
+    diffed_files.dedup();
+    assert_eq!(diffed_files, files, "stderr:\n{}", stderr);
+}
+
+#[ignore]
+#[test]
+fn config_path() {
+    // A file is used directly.
+    assert_that!(
+        &[
+            "--verbose",
+            "--check",
+            "--config-path",
+            "tests/config/small_tabs.toml",
+            "src/shape.rs"
+        ],
+        contains("Using rustfmt config file") && contains("small_tabs.toml")
+    );
+
+    // A directory is searched along with its parents.
+    assert_that!(
+        &[
+            "--verbose",
+            "--check",
+            "--config-path",
+            "tests/source",
+            "src/shape.rs"
+        ],
+        contains("Using rustfmt config file") && contains("rustfmt.toml")
+    );
+}
+
 #[ignore]
 #[test]
 fn inline_config() {
@@ -106,3 +240,18 @@ fn inline_config() {
             && contains("format_strings = true")
     );
 }
+
+#[ignore]
+#[test]
+fn json_parse_errors() {
+    assert_that!(
+        &[
+            "--check",
+            "--config=error_emission=Json",
+            "tests/parser/issue_4418.rs"
+        ],
+        starts_with("{\"message\":")
+            && contains("\"level\":\"error\"")
+            && contains("\"file_name\":\"tests/parser/issue_4418.rs\"")
+    );
+}
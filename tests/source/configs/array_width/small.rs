@@ -0,0 +1,11 @@
+// rustfmt-array_width: 20
+
+fn main() {
+    // should not be wrapped
+    let short = ["ab", "cd"];
+    let nested = [[1, 2], [3, 4]];
+
+    // should be wrapped
+    let longer = ["first one", "second one"];
+    let calls = [foo(1), bar(2), baz(3)];
+}
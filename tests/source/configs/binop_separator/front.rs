@@ -0,0 +1,16 @@
+// rustfmt-binop_separator: Front
+
+fn main() {
+    let or = foofoofoofoofoofoofoofoofoofoofoofoofoofoofoofoo || barbarbarbarbarbarbarbarbarbarbarbarbarbarbarbar;
+    let and = foofoofoofoofoofoofoofoofoofoofoofoofoofoofoofoo && barbarbarbarbarbarbarbarbarbarbarbarbarbarbarbar;
+    let sum = 123456789012345678901234567890 + 123456789012345678901234567890 + 123456789012345678901234567890;
+    let product = 123456789012345678901234567890 * 123456789012345678901234567890 * 123456789012345678901234567890;
+    let eq = foofoofoofoofoofoofoofoofoofoofoofoofoofoofoofoo == barbarbarbarbarbarbarbarbarbarbarbarbarbarbarbar;
+    let lt = foofoofoofoofoofoofoofoofoofoofoofoofoofoofoofoo < barbarbarbarbarbarbarbarbarbarbarbarbarbarbarbar;
+}
+
+fn or_patterns() {
+    match m {
+        Variant::Tag | Variant::Tag2 | Variant::Tag3 | Variant::Tag4 | Variant::Tag5 | Variant::Tag6 => {}
+    }
+}
@@ -0,0 +1,66 @@
+// rustfmt-brace_style: SameLineWhere
+// rustfmt-edition: 2018
+// Function brace style with modifiers
+
+pub fn lorem(ipsum: usize)
+{
+    // body
+}
+
+pub fn lorem<T>(ipsum: T) where T: Add + Sub {
+    // body
+}
+
+const fn lorem(ipsum: usize)
+{
+    // body
+}
+
+const fn lorem<T>(ipsum: T) where T: Add + Sub {
+    // body
+}
+
+async fn lorem(ipsum: usize)
+{
+    // body
+}
+
+async fn lorem<T>(ipsum: T) where T: Add + Sub {
+    // body
+}
+
+unsafe fn lorem(ipsum: usize)
+{
+    // body
+}
+
+unsafe fn lorem<T>(ipsum: T) where T: Add + Sub {
+    // body
+}
+
+extern "C" fn lorem(ipsum: usize)
+{
+    // body
+}
+
+extern "C" fn lorem<T>(ipsum: T) where T: Add + Sub {
+    // body
+}
+
+pub async unsafe fn lorem(ipsum: usize)
+{
+    // body
+}
+
+pub async unsafe fn lorem<T>(ipsum: T) where T: Add + Sub {
+    // body
+}
+
+pub const unsafe extern "C" fn lorem(ipsum: usize)
+{
+    // body
+}
+
+pub const unsafe extern "C" fn lorem<T>(ipsum: T) where T: Add + Sub {
+    // body
+}
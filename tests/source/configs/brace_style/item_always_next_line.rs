@@ -18,3 +18,9 @@ mod tests {
     #[test]
     fn it_works() {}
 }
+
+trait Sit { fn amet(&self); }
+
+union Consectetur { a: u32, b: f32 }
+
+impl Sit for Consectetur { fn amet(&self) {} }
@@ -0,0 +1,9 @@
+// rustfmt-closure_body_braces: true
+// Braces around closure bodies
+
+fn main() {
+    let lorem = |x| { let y = x + 1; y * 2 };
+    let ipsum = |x| { x + 1 };
+    let sit = |x| x + 1;
+    let dolor = |lorem| consectetur_adipiscing_elit(lorem, sed_do_eiusmod_tempor, incididunt_ut_labore_et_dolore_magna_aliqua);
+}
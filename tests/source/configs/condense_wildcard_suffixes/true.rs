@@ -3,4 +3,8 @@
 
 fn main() {
     let (lorem, ipsum, _, _) = (1, 2, 3, 4);
+    let Foo(lorem, _, _) = foo;
+    let (lorem, _) = (1, 2);
+    let (.., lorem, _, _) = (1, 2, 3, 4);
+    let [lorem, _, _] = [1, 2, 3];
 }
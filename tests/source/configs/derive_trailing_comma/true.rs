@@ -0,0 +1,9 @@
+// rustfmt-derive_trailing_comma: true
+// Trailing comma of wrapped derive lists
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Lorem;
+
+#[derive(Clone, Copy, Debug)]
+struct Ipsum;
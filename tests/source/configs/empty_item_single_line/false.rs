@@ -1,5 +1,5 @@
 // rustfmt-empty_item_single_line: false
-// Empty impl on single line
+// Empty items on single line
 
 impl Lorem {
 
@@ -14,3 +14,19 @@ fn lorem() {
 
 fn lorem() {
 }
+
+trait Dolor {
+
+}
+
+struct Sit {
+
+}
+
+enum Amet {
+
+}
+
+mod consectetur {
+
+}
@@ -31,4 +31,11 @@ enum LongWithSmallDiff {
     FocalPlaneColorimetryEstimates = 0x66706365,
     ReflectionHardcopyOriginalColorimetry = 0x72686F63,
     ReflectionPrintOutputColorimetry = 0x72706F63,
-}
\ No newline at end of file
+}
+enum WithAttributes {
+    /// Documented variants are still aligned
+    A = 1,
+    #[cfg(unix)]
+    AVeryLongVariantName = 2,
+    Bcdef = 3,
+}
@@ -0,0 +1,9 @@
+// rustfmt-empty_item_single_line: false
+// rustfmt-fn_empty_single_line: true
+// Empty fn on single line even when other empty items are not
+
+fn lorem() {
+}
+
+impl Lorem {
+}
@@ -1,5 +1,5 @@
-// rustfmt-fn_args_layout: Tall
-// Function arguments density
+// rustfmt-fn_params_layout: Tall
+// Function parameters layout
 
 trait Lorem {
     fn lorem(ipsum: Ipsum, dolor: Dolor, sit: Sit, amet: Amet);
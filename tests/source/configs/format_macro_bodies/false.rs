@@ -5,3 +5,6 @@ macro_rules! foo {
     ($a: ident $b: ident $c: ident) => { $a=$b+$c; };
 }
 
+macro_rules!   bar{
+    ($x:expr) => {   $x  +  1   };
+}
@@ -4,4 +4,6 @@
 
 fn main() {
     let lorem = "ipsum dolor sit amet consectetur adipiscing elit lorem ipsum dolor sit";
+    let ipsum = "sit amet\nconsectetur adipiscing";
+    let dolor = "sit amet \\n consectetur adipiscing elit";
 }
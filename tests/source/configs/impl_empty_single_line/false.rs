@@ -0,0 +1,7 @@
+// rustfmt-impl_empty_single_line: false
+// Empty impl not on single line even when other empty items are
+
+impl Lorem {}
+
+fn lorem() {
+}
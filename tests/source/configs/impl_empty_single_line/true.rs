@@ -0,0 +1,9 @@
+// rustfmt-empty_item_single_line: false
+// rustfmt-impl_empty_single_line: true
+// Empty impl on single line even when other empty items are not
+
+impl Lorem {
+}
+
+fn lorem() {
+}
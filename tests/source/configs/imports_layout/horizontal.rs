@@ -0,0 +1,13 @@
+// rustfmt-imports_layout: Horizontal
+
+use foo::{
+    aaa,
+    bbb::{ccc, ddd},
+};
+use std::{
+    env, fs,
+    io::{
+        Read,
+        Write,
+    },
+};
@@ -0,0 +1,8 @@
+// rustfmt-indent_style: Block
+// Chain indent with index, field access and try operators
+
+fn main() {
+    let lorem = ipsum.dolor[0].sit_amet().consectetur.adipiscing_elit().sed_do_eiusmod();
+
+    let lorem = ipsum()?.dolor_sit_amet()?.consectetur_adipiscing()?.elit_sed_do()?.eiusmod()?;
+}
@@ -0,0 +1,19 @@
+// rustfmt-inline_attribute_width: 50
+
+enum Foo {
+    #[default]
+    A,
+    #[cfg(feature = "b")]
+    B(u32),
+    #[cfg(feature = "c")]
+    #[allow(dead_code)]
+    C,
+    /// Doc comment.
+    D,
+    #[rustfmt::skip]
+    E(u8,  u16),
+    #[cfg(all(feature = "some_long_feature", feature = "another_long_feature"))]
+    F,
+    #[cfg(feature = "g")]
+    G { a: u32 },
+}
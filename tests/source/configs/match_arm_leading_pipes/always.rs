@@ -25,3 +25,13 @@ fn bar() {
         _ => {}
     }
 }
+
+fn attributes_and_guards() {
+    match x {
+        #[cfg(feature = "a")]
+        | Some(0) if a => {}
+        #[cfg(feature = "b")]
+        Some(1) | Some(2) if b => {}
+        _ => {}
+    }
+}
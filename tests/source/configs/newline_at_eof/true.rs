@@ -0,0 +1,8 @@
+// rustfmt-newline_at_eof: true
+// Newline at the end of the file
+
+fn main() {
+    println!("lorem ipsum");
+}
+
+
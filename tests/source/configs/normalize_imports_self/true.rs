@@ -0,0 +1,9 @@
+// rustfmt-normalize_imports_self: true
+// Normalise `self` in import lists
+
+use a::{self};
+use b::c::{self};
+use d::{e, self};
+use f::{g::{self}, h};
+use i::{j::{self}, j::k};
+use super::{self};
@@ -0,0 +1,7 @@
+// rustfmt-overflow_delimited_expr: false
+
+fn main() {
+    foo(bar, vec![aaaaaaaaaaaaaaaaaaaaaaaa, bbbbbbbbbbbbbbbbbbbbbbbb, cccccccccccccccccccccccc, dddddddddddddddddddddddd]);
+
+    foo(bar, (aaaaaaaaaaaaaaaaaaaaaaaa, bbbbbbbbbbbbbbbbbbbbbbbb, cccccccccccccccccccccccc, dddddddddddddddddddddddd));
+}
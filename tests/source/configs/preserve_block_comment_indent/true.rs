@@ -0,0 +1,14 @@
+// rustfmt-preserve_block_comment_indent: true
+// rustfmt-normalize_comments: true
+// Preserve banner block comments
+
+/*
+   +------------------+
+   |  Lorem ipsum     |
+   |     dolor sit    |
+   +------------------+
+*/
+fn lorem() {
+    /* sit amet */
+    let x = 1;
+}
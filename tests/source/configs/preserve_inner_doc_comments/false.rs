@@ -0,0 +1,11 @@
+// rustfmt-preserve_inner_doc_comments: false
+// rustfmt-wrap_comments: true
+// rustfmt-normalize_comments: true
+//! Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor incididunt ut labore et dolore magna aliqua.
+//!
+//!     +-------+  
+//!     | table |  
+//!     +-------+  
+
+/// Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor incididunt ut labore et dolore magna aliqua.
+fn foo() {}
@@ -0,0 +1,12 @@
+// rustfmt-preserve_inner_doc_comments: true
+// rustfmt-wrap_comments: true
+// rustfmt-normalize_comments: true
+//! Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor incididunt ut labore et dolore magna aliqua.
+//!
+//!     +-------+  
+//!     | table |  
+//!     +-------+  
+//! A line with a single trailing space. 
+
+/// Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor incididunt ut labore et dolore magna aliqua.
+fn foo() {}
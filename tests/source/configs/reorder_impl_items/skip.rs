@@ -0,0 +1,19 @@
+// rustfmt-reorder_impl_items: true
+
+impl Foo for Bar {
+    /// Second method, documented.
+    fn second(&self) {}
+
+    #[rustfmt::skip]
+    fn   anchored  ( & self ) {}
+
+    const B: u32 = 2;
+
+    /// First method, documented.
+    fn first(&self) {}
+
+    #[cfg(feature = "a")]
+    type Alias = u32;
+
+    const A: u32 = 1;
+}
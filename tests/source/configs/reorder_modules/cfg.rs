@@ -0,0 +1,12 @@
+// rustfmt-reorder_modules: true
+// Modules guarded by different `cfg` attributes are not reordered together
+
+mod sit;
+mod lorem;
+#[cfg(test)]
+mod ipsum;
+#[cfg(test)]
+/// Dolor.
+mod dolor;
+mod b {}
+mod a {}
@@ -0,0 +1,3 @@
+fn skip_formatting_included() {
+    println ! ( "Skip this as well" ) ;
+}
@@ -2,3 +2,7 @@
 
 mod foo  ;
 mod void;
+#[path = "bar/baz.rs"]
+mod   baz;
+
+include!(  "included.rs"  );
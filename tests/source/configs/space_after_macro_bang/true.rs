@@ -0,0 +1,20 @@
+// rustfmt-space_after_macro_bang: true
+
+fn main() {
+    let v = vec![1, 2, 3];
+    let w = vec![0; 10];
+    println!("{}", !cond);
+    assert!(!v.is_empty());
+    let e = empty!();
+    foo! {
+        bar
+    }
+}
+
+fn special_case_macros() {
+    let p = eprint!("{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}", 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+    let q = eprint!["{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}", 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+    eprint! {
+        "{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}", 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15
+    }
+}
@@ -0,0 +1,8 @@
+// rustfmt-space_around_attr_eq: false
+// Space around attribute `=`
+
+#[doc = "a = b"]
+#[path  =  "foo.rs"]
+#[cfg_attr(feature = "serde", serde(rename = "x = y"))]
+#[my_attr(a == b, c = d)]
+struct Lorem;
@@ -0,0 +1,22 @@
+// rustfmt-space_before_fn_paren: false
+
+fn lorem(ipsum: usize) -> usize {
+    dolor(ipsum)
+}
+
+fn sit<T>(amet: T) {}
+
+trait Consectetur {
+    fn adipiscing(&self);
+}
+
+impl Consectetur for Elit {
+    fn adipiscing(&self) {
+        let f = |x| x + 1;
+        f(1);
+    }
+}
+
+extern "C" {
+    fn sed(do_: i32);
+}
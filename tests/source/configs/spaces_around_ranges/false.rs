@@ -32,3 +32,10 @@ fn half_open() {
     if let .. 5 = 0 {}
     if let 5 .. = 0 {}
 }
+
+fn not_ranges() {
+    let lorem = Lorem { ipsum: 0, ..base };
+    let [first, .., last] = array;
+    let (first, ..) = tuple;
+    let Lorem { ipsum, .. } = lorem;
+}
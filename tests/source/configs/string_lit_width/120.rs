@@ -0,0 +1,8 @@
+// rustfmt-format_strings: true
+// rustfmt-string_lit_width: 120
+// String literal width
+
+fn main() {
+    let lorem = "ipsum dolor sit amet consectetur adipiscing elit lorem ipsum dolor sit amet consectetur";
+    let lorem = "ipsum dolor sit amet\nconsectetur adipiscing elit\nlorem ipsum dolor sit amet consectetur adipiscing elit";
+}
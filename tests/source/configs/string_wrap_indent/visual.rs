@@ -0,0 +1,7 @@
+// rustfmt-string_wrap_indent: Visual
+// rustfmt-format_strings: true
+// rustfmt-max_width: 50
+
+fn main() {
+    let lorem = "Lorem ipsum dolor sit amet consectetur adipiscing elit sed do eiusmod tempor";
+}
@@ -0,0 +1,13 @@
+// rustfmt-struct_field_align_threshold: 20
+// rustfmt-version: Two
+// Fields with attributes or doc comments start a new alignment group
+
+struct Attributes {
+    width: usize,
+    tab_spaces: usize,
+    /// Whether to print more.
+    verbose: bool,
+    #[serde(default)]
+    x: u8,
+    height: u16,
+}
@@ -0,0 +1,6 @@
+// rustfmt-struct_lit_single_line_width: 0
+
+fn main() {
+    let c = Lorem { ipsum: dolor };
+    let d = SomeLongerName { a: 1 };
+}
@@ -0,0 +1,34 @@
+// rustfmt-trailing_semicolon: false
+
+fn main() {
+    match x {
+        Some(y) => foo(y),
+        None => (),
+    };
+
+    match x {
+        Some(y) => { foo(y); }
+        None => {}
+    };
+
+    if x { foo(); } else { bar(); };
+
+    unsafe { foo(); };
+
+    {
+        let y = 1;
+    };
+
+    // The value of the block-like expression is not `()`, so the semicolon is required.
+    match x {
+        Some(y) => y,
+        None => 0,
+    };
+
+    if x { 1 } else { 2 };
+
+    let y = match x {
+        Some(y) => { foo(y); }
+        None => {}
+    };
+}
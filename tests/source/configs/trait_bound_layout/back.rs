@@ -0,0 +1,14 @@
+// rustfmt-trait_bound_layout: Back
+// Supertraits one per line
+
+pub trait Number: Copy + Eq + Not<Output = Self> + Shl<u8, Output = Self> + Shr<u8, Output = Self> + BitAnd<Self, Output = Self> + BitOr<Self, Output = Self> {
+    fn zero() -> Self;
+}
+
+trait Lorem: Ipsum + Dolor {
+    fn sit();
+}
+
+trait Consectetur<T>: Adipiscing + Elit + SedDoEiusmod + Tempor + Incididunt + UtLaboreEtDolore + MagnaAliqua + UtEnimAdMinim where T: Veniam {
+    fn quis();
+}
@@ -35,3 +35,11 @@ where 'a: 'b + 'c
 fn Foo<T = Foo, Output = Expr<'tcx> + Foo>() {
     let i = 6;
 }
+
+fn dyn_bounds(a: &(dyn Read+Send), b: Box<dyn Fn()+Send+'static>) {}
+
+fn impl_bounds(a: impl Iterator<Item=u8>+Send) -> impl Fn()+'a {}
+
+trait Lending {
+    type Item<'a>: Iterator<Item=&'a u8>+'a;
+}
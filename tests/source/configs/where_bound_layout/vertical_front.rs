@@ -0,0 +1,17 @@
+// rustfmt-where_bound_layout: VerticalFront
+// Where bound layout
+
+fn lorem<T>(ipsum: T)
+where
+    T: Add + Sub + Mul + Div,
+{
+    // body
+}
+
+fn lorem<Ipsum, Dolor>(ipsum: Ipsum, dolor: Dolor)
+where
+    Ipsum: LoremIpsumDolorSitAmetConsectetur + AdipiscingElitSedDoEiusmodTempor + IncididuntUtLaboreEtDolore,
+    Dolor: Clone,
+{
+    // body
+}
@@ -23,4 +23,8 @@ fn lorem<Ipsum, Dolor, Sit, Amet>() -> T where Ipsum: Eq {
     // body
 }
 
+fn lorem_too_long<Ipsum, Dolor, Sit, Amet>(ipsum: Ipsum, dolor: Dolor, sit: Sit) -> Amet where Ipsum: Eq {
+    // body
+}
+
 unsafe impl Sync for Foo where (): Send {}
@@ -0,0 +1,12 @@
+// Imports between the fence markers are kept in source order.
+
+use b;
+use a;
+
+// rustfmt::import-order-begin
+use zed;
+use alpha;
+// rustfmt::import-order-end
+
+use d;
+use c;
@@ -105,3 +105,6 @@ use std::f64::consts::{SQRT_2, E, PI};
 // #3273
 #[rustfmt::skip]
 use std::fmt::{self, {Display, Formatter}};
+
+// Braces around a single item with a comment are kept
+use foo::{/* comment */ Bar};
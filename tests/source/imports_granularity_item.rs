@@ -4,3 +4,6 @@ use a::{b, c, d};
 use a::{f::g, h::{i, j}};
 use a::{l::{self, m, n::o, p::*}};
 use a::q::{self};
+use a::r::{self as s, t as u};
+#[cfg(test)]
+use a::{v, w};
@@ -0,0 +1,10 @@
+// rustfmt-wrap_comments: true
+// rustfmt-max_width: 50
+
+/// Lorem ipsum dolor sit amet, consectetur adipiscing elit
+/// # Examples
+/// Sed do eiusmod tempor incididunt ut labore
+///
+/// ## A rather long section header that must not be wrapped
+/// Ut enim ad minim veniam
+fn foo() {}
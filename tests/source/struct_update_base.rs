@@ -0,0 +1,13 @@
+// Functional record update syntax
+
+fn main() {
+    let lorem = Lorem { a,   .. base };
+    let lorem = Lorem { ipsum: dolor_sit_amet, consectetur: adipiscing_elit, sed: do_eiusmod, ..base };
+    let lorem = Lorem { ipsum: dolor_sit_amet, consectetur: adipiscing_elit, sed: do_eiusmod,
+        .. Default::default() };
+}
+
+fn long_base() {
+    let lorem = Lorem { ipsum: 1, ..Lorem::with_capacity_and_hasher(some_capacity, RandomState::default()) };
+    let lorem = Lorem { ipsum: 1, ..Lorem::with_capacity_and_hasher(some_initial_capacity, RandomState::with_seeds(1, 2, 3, 4)) };
+}
@@ -0,0 +1,16 @@
+// Binary expressions
+
+fn foo() {
+    // 100
+    let x = aaaaaaaaaa || bbbbbbbbbb || cccccccccc || dddddddddd && eeeeeeeeee || ffffffffff || ggg;
+    // 101
+    let x =
+        aaaaaaaaaa || bbbbbbbbbb || cccccccccc || dddddddddd && eeeeeeeeee || ffffffffff || gggg;
+    // 104
+    let x = aaaaaaaaaa
+        || bbbbbbbbbb
+        || cccccccccc
+        || dddddddddd && eeeeeeeeee
+        || ffffffffff
+        || gggggggg;
+}
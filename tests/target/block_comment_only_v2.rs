@@ -0,0 +1,18 @@
+// rustfmt-version: Two
+// Blank lines right inside the braces of a block which only has comments are removed.
+
+fn foo() {
+    // Only a comment.
+}
+
+fn bar() {
+    // First comment.
+
+    // Second comment.
+}
+
+fn baz() {
+    let x = 1;
+
+    // Trailing comment.
+}
@@ -0,0 +1,7 @@
+// rustfmt-wrap_comments: true
+// rustfmt-max_width: 50
+
+/// Refer to crate::lorem::ipsum::dolor::sit::amet::consectetur.
+/// Call `lorem.ipsum().dolor().sit().amet().consectetur()` first.
+/// Lorem ipsum dolor sit amet consectetur `sed do` eiusmod.
+fn lorem() {}
@@ -0,0 +1,14 @@
+// rustfmt-wrap_comments: true
+
+// Pendant la nuit du 9 mars 1860, les nuages, se confondant avec la mer,
+// limitaient à quelques brasses la portée de la vue. Sur cette mer démontée,
+// dont les lames déferlaient en projetant des lueurs livides, un léger bâtiment
+// fuyait presque à sec de toile.
+
+pub mod foo {}
+
+// ゆく河の流れは絶えずして、しかももとの水にあらず。淀みに浮かぶうたかたは、
+// かつ消えかつ結びて、久しくとどまりたるためしなし。世の中にある人とすみかと、
+// またかくのごとし。
+
+pub mod bar {}
@@ -0,0 +1,4 @@
+// rustfmt-normalize_comments: true
+// Block comments followed by CRLF newlines should not an extra newline at the end
+
+// Something else
@@ -0,0 +1,19 @@
+// rustfmt-blank_lines_between_match_arms: 1
+// Blank lines between match arms
+
+fn main() {
+    match x {
+        A => 1,
+        B => 2,
+
+        C => 3,
+
+        D => 4,
+
+        // Comment about E
+        E => 5,
+        F => 6, // Trailing comment
+
+        G => 7,
+    }
+}
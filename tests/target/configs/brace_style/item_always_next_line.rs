@@ -0,0 +1,41 @@
+// rustfmt-brace_style: AlwaysNextLine
+// Item brace style
+
+enum Foo {}
+
+struct Bar {}
+
+struct Lorem
+{
+    ipsum: bool,
+}
+
+struct Dolor<T>
+where
+    T: Eq,
+{
+    sit: T,
+}
+
+#[cfg(test)]
+mod tests
+{
+    #[test]
+    fn it_works() {}
+}
+
+trait Sit
+{
+    fn amet(&self);
+}
+
+union Consectetur
+{
+    a: u32,
+    b: f32,
+}
+
+impl Sit for Consectetur
+{
+    fn amet(&self) {}
+}
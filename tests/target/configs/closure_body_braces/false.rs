@@ -0,0 +1,11 @@
+// rustfmt-closure_body_braces: false
+// Braces around closure bodies
+
+fn main() {
+    let lorem = |x| {
+        let y = x + 1;
+        y * 2
+    };
+    let ipsum = |x| x + 1;
+    let sit = |x| x + 1;
+}
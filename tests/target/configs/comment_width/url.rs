@@ -0,0 +1,7 @@
+// rustfmt-comment_width: 40
+// rustfmt-wrap_comments: true
+// Comment width with URLs
+
+fn main() {
+    // See https://example.com/a/very/long/path/that/cannot/be/broken
+}
@@ -0,0 +1,6 @@
+// rustfmt-condense_wildcard_suffixes: false
+// Condense wildcard suffixes
+
+fn main() {
+    let (lorem, ipsum, _, _) = (1, 2, 3, 4);
+}
@@ -0,0 +1,10 @@
+// rustfmt-condense_wildcard_suffixes: true
+// Condense wildcard suffixes
+
+fn main() {
+    let (lorem, ipsum, ..) = (1, 2, 3, 4);
+    let Foo(lorem, ..) = foo;
+    let (lorem, _) = (1, 2);
+    let (.., lorem, _, _) = (1, 2, 3, 4);
+    let [lorem, _, _] = [1, 2, 3];
+}
@@ -0,0 +1,17 @@
+// rustfmt-continuation_indent_width: 8
+// Continuation indent width
+
+fn main() {
+    let lorem = ipsum_dolor_sit
+            .sit()
+            .amet()
+            .consectetur()
+            .adipiscing()
+            .elit()
+            .sed();
+    let consectetur_adipiscing_elit_sed_do_eiusmod_tempor =
+            incididunt_ut_labore(et, dolore, magna, aliqua);
+    if true {
+        magna_aliqua = ut_enim_ad_minim_veniam(quis, nostrud, exercitation, ullamco);
+    }
+}
@@ -0,0 +1,6 @@
+// rustfmt-disable_all_formatting: true
+// Disable all formatting
+
+fn main() {
+    if lorem{println!("ipsum!");}else{println!("dolor!");}
+}
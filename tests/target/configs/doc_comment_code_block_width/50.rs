@@ -0,0 +1,14 @@
+// rustfmt-format_code_in_doc_comments: true
+// rustfmt-doc_comment_code_block_width: 50
+
+/// ```
+/// let lorem = ipsum(
+///     dolor,
+///     sit,
+///     amet,
+///     consectetur,
+///     adipiscing,
+/// );
+/// let short = ipsum(dolor, sit);
+/// ```
+fn foo() {}
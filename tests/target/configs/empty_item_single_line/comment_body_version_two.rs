@@ -0,0 +1,15 @@
+// rustfmt-empty_item_single_line: true
+// rustfmt-version: Two
+// Functions whose body is only a comment
+
+fn lorem() { /* TODO */ }
+
+fn ipsum() { /* a short comment */ }
+
+fn dolor() {
+    /* the comment is on its own line */
+}
+
+fn adipiscing_elit_sed_do_eiusmod_tempor(incididunt: UtLaboreEtDolore) {
+    /* labore et dolore magna aliqua */
+}
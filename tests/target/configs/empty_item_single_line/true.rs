@@ -0,0 +1,18 @@
+// rustfmt-empty_item_single_line: true
+// Empty items on single line
+
+impl Lorem {}
+
+impl Ipsum {}
+
+fn lorem() {}
+
+fn lorem() {}
+
+trait Dolor {}
+
+struct Sit {}
+
+enum Amet {}
+
+mod consectetur {}
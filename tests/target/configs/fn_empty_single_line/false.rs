@@ -0,0 +1,7 @@
+// rustfmt-fn_empty_single_line: false
+// Empty fn not on single line even when other empty items are
+
+fn lorem() {
+}
+
+impl Lorem {}
@@ -0,0 +1,12 @@
+// rustfmt-fn_generics_space: Both
+// Spaces around the generic parameters of a function
+
+fn lorem <T> (ipsum: T) {}
+
+fn sit(amet: usize) {}
+
+impl Foo {
+    fn dolor <T: Clone> (&self, ipsum: T) -> T {
+        ipsum.clone()
+    }
+}
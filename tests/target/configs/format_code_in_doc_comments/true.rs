@@ -0,0 +1,42 @@
+// rustfmt-format_code_in_doc_comments: true
+
+/// Formatted:
+///
+/// ```
+/// let x = 1;
+/// ```
+///
+/// ```rust
+/// let x = 1;
+/// ```
+///
+/// ```should_panic
+/// let x = 1;
+/// ```
+///
+/// Left alone:
+///
+/// ```text
+/// let x=1;
+/// ```
+///
+/// ```bash
+/// cargo  fmt
+/// ```
+///
+/// ```ignore
+/// let x=1;
+/// ```
+///
+/// ```no_run
+/// let x=1;
+/// ```
+///
+/// ```compile_fail
+/// let x=1;
+/// ```
+///
+/// ```rust,ignore
+/// let x=1;
+/// ```
+fn foo() {}
@@ -0,0 +1,6 @@
+// rustfmt-format_generated_files: true
+// @generated by a code generator. Do not edit.
+
+fn main() {
+    foo();
+}
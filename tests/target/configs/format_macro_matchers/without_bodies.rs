@@ -0,0 +1,7 @@
+// rustfmt-format_macro_matchers: true
+// rustfmt-format_macro_bodies: false
+
+macro_rules! foo {
+    ($a:ident : $b:ty) => { $a(42): $b; };
+    ($a:ident $b:ident $c:ident) => { $a=$b+$c; };
+}
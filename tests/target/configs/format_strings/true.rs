@@ -0,0 +1,13 @@
+// rustfmt-format_strings: true
+// rustfmt-max_width: 50
+// Force format strings
+
+fn main() {
+    let lorem = "ipsum dolor sit amet \
+                 consectetur adipiscing elit \
+                 lorem ipsum dolor sit";
+    let ipsum =
+        "sit amet\nconsectetur adipiscing";
+    let dolor = "sit amet \\n consectetur \
+                 adipiscing elit";
+}
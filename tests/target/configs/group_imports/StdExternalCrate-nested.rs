@@ -0,0 +1,7 @@
+// rustfmt-group_imports: StdExternalCrate
+mod test {
+    use std::path;
+
+    use crate::foo::bar;
+    use crate::foo::bar2;
+}
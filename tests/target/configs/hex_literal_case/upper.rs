@@ -0,0 +1,9 @@
+// rustfmt-hex_literal_case: Upper
+
+fn main() {
+    let h1 = 0xABCD_EF01u32;
+    let h2 = 0xFFi64;
+    let d = 1E10f64;
+    let o = 0o777;
+    let b = 0b1010_1010;
+}
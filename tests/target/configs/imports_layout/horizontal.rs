@@ -0,0 +1,4 @@
+// rustfmt-imports_layout: Horizontal
+
+use foo::{aaa, bbb::{ccc, ddd}};
+use std::{env, fs, io::{Read, Write}};
@@ -0,0 +1,5 @@
+// rustfmt-imports_indent: Block
+// rustfmt-imports_granularity: Crate
+// rustfmt-imports_layout: Mixed
+
+use std::{fmt, io, str, str::FromStr};
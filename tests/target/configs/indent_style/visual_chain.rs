@@ -0,0 +1,11 @@
+// rustfmt-indent_style: Visual
+// Chain indent
+
+fn main() {
+    let lorem = ipsum.dolor()
+                     .sit()
+                     .amet()
+                     .consectetur()
+                     .adipiscing()
+                     .elite();
+}
@@ -0,0 +1,7 @@
+// rustfmt-indent_style: Visual
+// Struct literal-style
+
+fn main() {
+    let lorem = Lorem { ipsum: dolor,
+                        sit: amet };
+}
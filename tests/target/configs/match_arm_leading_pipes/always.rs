@@ -0,0 +1,37 @@
+// rustfmt-match_arm_leading_pipes: Always
+
+fn foo() {
+    match foo {
+        | "foo" | "bar" => {}
+        | "baz"
+        | "something relatively long"
+        | "something really really really realllllllllllllly long" => println!("x"),
+        | "qux" => println!("y"),
+        | _ => {}
+    }
+}
+
+fn issue_3973() {
+    match foo {
+        | "foo" | "bar" => {}
+        | _ => {}
+    }
+}
+
+fn bar() {
+    match baz {
+        | "qux" => {}
+        | "foo" | "bar" => {}
+        | _ => {}
+    }
+}
+
+fn attributes_and_guards() {
+    match x {
+        #[cfg(feature = "a")]
+        | Some(0) if a => {}
+        #[cfg(feature = "b")]
+        | Some(1) | Some(2) if b => {}
+        | _ => {}
+    }
+}
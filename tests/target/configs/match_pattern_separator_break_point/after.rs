@@ -0,0 +1,13 @@
+// rustfmt-match_pattern_separator_break_point: After
+
+fn main() {
+    match m {
+        Variant::Tag |
+        Variant::Tag2 |
+        Variant::Tag3 |
+        Variant::Tag4 |
+        Variant::Tag5 |
+        Variant::Tag6 => {}
+        Variant::Short | Variant::Other => {}
+    }
+}
@@ -0,0 +1,14 @@
+// rustfmt-match_pattern_separator_break_point: Before
+// rustfmt-binop_separator: Back
+
+fn main() {
+    match m {
+        Variant::Tag
+        | Variant::Tag2
+        | Variant::Tag3
+        | Variant::Tag4
+        | Variant::Tag5
+        | Variant::Tag6 => {}
+        Variant::Short | Variant::Other => {}
+    }
+}
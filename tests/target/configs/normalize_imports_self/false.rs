@@ -0,0 +1,9 @@
+// rustfmt-normalize_imports_self: false
+// Normalise `self` in import lists
+
+use super::{self};
+use a::{self};
+use b::c::{self};
+use d::{self, e};
+use f::{g::{self}, h};
+use i::{j::k, j::{self}};
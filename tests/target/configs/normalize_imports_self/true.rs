@@ -0,0 +1,9 @@
+// rustfmt-normalize_imports_self: true
+// Normalise `self` in import lists
+
+use super::{self};
+use a;
+use b::c;
+use d::{self, e};
+use f::{g, h};
+use i::{j, j::k};
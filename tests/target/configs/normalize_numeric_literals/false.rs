@@ -0,0 +1,17 @@
+// rustfmt-normalize_numeric_literals: false
+
+fn main() {
+    let a = 0xFF;
+    let b = 0xDEAD_BEEFu32;
+    let c = 0o777i64;
+    let d = 0b1010_1010u8;
+    let e = 1_000;
+    let f = 1000000usize;
+    let g = 1.5E10;
+    let h = 2E-3f64;
+
+    match x {
+        0xAB..=0xCD => {}
+        _ => {}
+    }
+}
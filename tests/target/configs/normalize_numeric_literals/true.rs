@@ -0,0 +1,17 @@
+// rustfmt-normalize_numeric_literals: true
+
+fn main() {
+    let a = 0xff;
+    let b = 0xdead_beefu32;
+    let c = 0o777i64;
+    let d = 0b1010_1010u8;
+    let e = 1_000;
+    let f = 1000000usize;
+    let g = 1.5e10;
+    let h = 2e-3f64;
+
+    match x {
+        0xab..=0xcd => {}
+        _ => {}
+    }
+}
@@ -0,0 +1,17 @@
+// rustfmt-overflow_delimited_expr: true
+
+fn main() {
+    foo(bar, vec![
+        aaaaaaaaaaaaaaaaaaaaaaaa,
+        bbbbbbbbbbbbbbbbbbbbbbbb,
+        cccccccccccccccccccccccc,
+        dddddddddddddddddddddddd,
+    ]);
+
+    foo(bar, (
+        aaaaaaaaaaaaaaaaaaaaaaaa,
+        bbbbbbbbbbbbbbbbbbbbbbbb,
+        cccccccccccccccccccccccc,
+        dddddddddddddddddddddddd,
+    ));
+}
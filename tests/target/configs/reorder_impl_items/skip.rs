@@ -0,0 +1,18 @@
+// rustfmt-reorder_impl_items: true
+
+impl Foo for Bar {
+    #[cfg(feature = "a")]
+    type Alias = u32;
+
+    #[rustfmt::skip]
+    fn   anchored  ( & self ) {}
+
+    const B: u32 = 2;
+    const A: u32 = 1;
+
+    /// Second method, documented.
+    fn second(&self) {}
+
+    /// First method, documented.
+    fn first(&self) {}
+}
@@ -0,0 +1,12 @@
+// rustfmt-reorder_modules: true
+// Modules guarded by different `cfg` attributes are not reordered together
+
+mod lorem;
+mod sit;
+#[cfg(test)]
+/// Dolor.
+mod dolor;
+#[cfg(test)]
+mod ipsum;
+mod b {}
+mod a {}
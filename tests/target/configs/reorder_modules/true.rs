@@ -0,0 +1,7 @@
+// rustfmt-reorder_modules: true
+// Reorder modules
+
+mod dolor;
+mod ipsum;
+mod lorem;
+mod sit;
@@ -0,0 +1,16 @@
+// rustfmt-short_array_element_width_threshold: 10
+
+pub const MASKS: [u64; 8] = [
+    0x0101010101,
+    0x0202020202,
+    0x0404040404,
+    0x0808080808,
+    0x1010101010,
+    0x2020202020,
+    0x4040404040,
+    0x8080808080,
+];
+
+const TABLE: [u8; 24] = [
+    0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23,
+];
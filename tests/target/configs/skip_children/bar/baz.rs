@@ -0,0 +1,3 @@
+fn skip_formatting_this_too() {
+    println ! ( "Skip this too" ) ;
+}
@@ -0,0 +1,8 @@
+// rustfmt-skip_children: true
+
+mod foo;
+mod void;
+#[path = "bar/baz.rs"]
+mod baz;
+
+include!("included.rs");
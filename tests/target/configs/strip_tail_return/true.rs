@@ -0,0 +1,58 @@
+// rustfmt-strip_tail_return: true
+// Return at the end of a function body
+
+fn lorem(x: u32) -> u32 {
+    let y = x + 1;
+    y * 2
+}
+
+fn ipsum(x: u32) -> u32 {
+    x
+}
+
+fn dolor() {
+    sit();
+    return;
+}
+
+fn amet(xs: &[u32]) -> Option<u32> {
+    for x in xs {
+        if *x > 1 {
+            return Some(*x);
+        }
+    }
+    None
+}
+
+fn consectetur(x: bool) -> u32 {
+    if x {
+        return 1;
+    }
+    loop {
+        return 2;
+    }
+}
+
+fn adipiscing() -> u32 {
+    // the answer
+    return /* not stripped */ 42;
+}
+
+impl Lorem {
+    fn elit(&self) -> u32 {
+        self.sed
+    }
+}
+
+fn sed(v: Vec<u32>) -> usize {
+    let c = RefCell::new(v);
+    return c.borrow().len();
+}
+
+fn tempor(x: u32) -> u32 {
+    let f = |x: u32| {
+        let y = x + 1;
+        y * 2
+    };
+    f(x)
+}
@@ -0,0 +1,10 @@
+// rustfmt-struct_field_align_threshold: 20
+// Blank lines start a new alignment group
+
+struct Config {
+    width:      usize,
+    tab_spaces: usize,
+
+    x:       u8,
+    verbose: bool,
+}
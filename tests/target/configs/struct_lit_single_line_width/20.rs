@@ -0,0 +1,15 @@
+// rustfmt-struct_lit_single_line_width: 20
+
+fn main() {
+    // should not be wrapped
+    let a = Foo { x: 1 };
+    let b = Lorem { ipsum: 1 };
+
+    // should be wrapped
+    let c = Lorem {
+        ipsum: dolor,
+    };
+    let d = SomeLongerName {
+        a: 1,
+    };
+}
@@ -0,0 +1,8 @@
+// rustfmt-trim_comment_trailing_whitespace: false
+
+// A comment   
+// with trailing whitespace 
+fn main() {
+    /* a block 
+     * comment  */
+}
@@ -0,0 +1,6 @@
+// rustfmt-use_try_shorthand: false
+// Use try! shorthand
+
+fn main() {
+    let lorem = try!(ipsum.map(|dolor| dolor.sit()));
+}
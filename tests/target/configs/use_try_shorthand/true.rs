@@ -0,0 +1,6 @@
+// rustfmt-use_try_shorthand: true
+// Use try! shorthand
+
+fn main() {
+    let lorem = ipsum.map(|dolor| dolor.sit())?;
+}
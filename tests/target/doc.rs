@@ -0,0 +1,5 @@
+// rustfmt-normalize_comments: true
+// Part of multiple.rs
+
+// sadfsdfa
+// sdffsdfasdf
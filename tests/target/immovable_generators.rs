@@ -0,0 +1,7 @@
+#![feature(generators)]
+
+unsafe fn foo() {
+    let mut ga = static || {
+        yield 1;
+    };
+}
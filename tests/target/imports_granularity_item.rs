@@ -0,0 +1,19 @@
+// rustfmt-imports_granularity: Item
+
+use a::b;
+use a::c;
+use a::d;
+use a::f::g;
+use a::h::i;
+use a::h::j;
+use a::l;
+use a::l::m;
+use a::l::n::o;
+use a::l::p::*;
+use a::q;
+use a::r as s;
+use a::r::t as u;
+#[cfg(test)]
+use a::v;
+#[cfg(test)]
+use a::w;
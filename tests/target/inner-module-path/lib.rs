@@ -0,0 +1,8 @@
+#[path = "."]
+mod a {
+    mod b;
+}
+
+mod c {
+    mod d;
+}
@@ -0,0 +1,3 @@
+fn issue_1055() {
+    let foo = (|| {})();
+}
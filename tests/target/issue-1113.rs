@@ -0,0 +1,33 @@
+pub fn foo() -> fmt::Result
+//pub fn writeStringToken
+{
+    panic!()
+}
+
+pub fn foo() -> fmt::Result // pub fn writeStringToken
+{
+    panic!()
+}
+
+pub fn foo() -> fmt::Result /* pub fn writeStringToken */ {
+    panic!()
+}
+
+pub fn foo() -> fmt::Result
+/* pub fn writeStringToken */ {
+    panic!()
+}
+
+pub fn foo() -> fmt::Result
+/* pub fn writeStringToken */
+{
+    panic!()
+}
+
+pub fn foo() -> fmt::Result /*
+                             *
+                             *
+                             */
+{
+    panic!()
+}
@@ -0,0 +1,3 @@
+trait T {
+    itemmacro!(this, is.now().formatted(yay));
+}
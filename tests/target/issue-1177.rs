@@ -0,0 +1,7 @@
+// rustfmt-normalize_comments: true
+fn main() {
+    // Line Comment
+    // Block Comment
+
+    let d = 5;
+}
@@ -0,0 +1,7 @@
+// rustfmt-format_strings: true
+// rustfmt-max_width: 50
+
+const foo: String =
+    "trailing_spaces!!            
+                keep them! Amet neque. Praesent \
+     rhoncus eros non velit.";
@@ -0,0 +1,8 @@
+/*!
+# Example
+
+```
+        // Here goes some example
+```
+ */
+struct Item;
@@ -0,0 +1,6 @@
+fn main() {
+    //foo
+    /*
+     */
+    format!("hello");
+}
@@ -0,0 +1,6 @@
+// #1624
+pub unsafe fn some_long_function_name(
+    arg1: Type1,
+    arg2: Type2,
+) -> (SomeLongTypeName, AnotherLongTypeName, AnotherLongTypeName) {
+}
@@ -0,0 +1,10 @@
+fn issue1693() {
+    let pixel_data = vec![
+        (
+            f16::from_f32(0.82),
+            f16::from_f32(1.78),
+            f16::from_f32(0.21)
+        );
+        256 * 256
+    ];
+}
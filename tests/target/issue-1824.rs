@@ -0,0 +1,5 @@
+pub trait Ingredient
+where
+    Self: Send,
+{
+}
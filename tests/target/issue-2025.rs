@@ -0,0 +1,4 @@
+// See if rustfmt removes empty lines on top of the file.
+pub fn foo() {
+    println!("hello, world");
+}
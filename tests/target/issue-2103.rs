@@ -0,0 +1,14 @@
+struct X
+where
+    i32: Sized,
+{
+    x: i32,
+}
+
+struct X
+// with comment
+where
+    i32: Sized,
+{
+    x: i32,
+}
@@ -0,0 +1,6 @@
+// rustfmt-wrap_comments: true
+
+//hello
+//world
+
+fn main() {}
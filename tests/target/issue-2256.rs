@@ -0,0 +1,7 @@
+// こんにちは
+use std::borrow::Cow;
+
+/* comment 1 */
+/* comment 2 */
+
+/* comment 3 */
@@ -0,0 +1,4 @@
+// rustfmt-normalize_comments: true
+// the following empty comment should not have any trailing space added.
+//
+fn main() {}
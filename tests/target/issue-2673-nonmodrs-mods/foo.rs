@@ -0,0 +1,4 @@
+// rustfmt-config: skip_children.toml
+mod bar;
+
+mod baz {}
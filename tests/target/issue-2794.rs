@@ -0,0 +1,12 @@
+// rustfmt-indent_style: Block
+// rustfmt-imports_indent: Block
+// rustfmt-imports_layout: Vertical
+
+use std::{
+    env,
+    fs,
+    io::{
+        Read,
+        Write,
+    },
+};
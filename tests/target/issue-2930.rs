@@ -0,0 +1,5 @@
+// rustfmt-indent_style: Visual
+fn main() {
+    let (first_variable, second_variable) = (this_is_something_with_an_extraordinarily_long_name,
+                                             this_variable_name_is_also_pretty_long);
+}
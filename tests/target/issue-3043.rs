@@ -0,0 +1,5 @@
+// rustfmt-edition: 2018
+
+use ::std::vec::Vec;
+
+fn main() {}
@@ -0,0 +1,45 @@
+// rustfmt-indent_style: Visual
+fn main() {
+    something.aaaaaaaaaaaa()
+             .aaaaaaaaaaaa()
+             .aaaaaaaaaaaa()
+             .aaaaaaaaaaaa()
+             .aaaaaaaaaaaa()
+             .aaaaaaaaaaaa()
+             .aaaaaaaaaaaa()
+             .bench_function(|| {
+                 let x = hello();
+             });
+
+    something.aaaaaaaaaaaa()
+             .aaaaaaaaaaaa()
+             .aaaaaaaaaaaa()
+             .aaaaaaaaaaaa()
+             .aaaaaaaaaaaa()
+             .aaaaaaaaaaaa()
+             .aaaaaaaaaaaa()
+             .bench_function(arg, || {
+                 let x = hello();
+             });
+
+    something.aaaaaaaaaaaa()
+             .aaaaaaaaaaaa()
+             .aaaaaaaaaaaa()
+             .aaaaaaaaaaaa()
+             .aaaaaaaaaaaa()
+             .aaaaaaaaaaaa()
+             .aaaaaaaaaaaa()
+             .bench_function(arg,
+                             || {
+                                 let x = hello();
+                             },
+                             arg);
+
+    AAAAAAAAAAA.function(|| {
+                   let _ = ();
+               });
+
+    AAAAAAAAAAA.chain().function(|| {
+                           let _ = ();
+                       })
+}
@@ -0,0 +1,14 @@
+pub fn fail1() {
+    // Some comment.
+    /**///
+}
+
+pub fn fail2() {
+    // Some comment.
+    /**/
+}
+
+pub fn fail3() {
+    // Some comment.
+    //
+}
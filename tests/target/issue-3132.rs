@@ -0,0 +1,15 @@
+// rustfmt-version: Two
+
+fn test() {
+    /*
+    a
+    */
+    let x = 42;
+    /*
+    aaa
+    "line 1
+  line 2
+        line 3"
+    */
+    let x = 42;
+}
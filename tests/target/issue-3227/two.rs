@@ -0,0 +1,15 @@
+// rustfmt-version: Two
+
+fn main() {
+    thread::spawn(|| {
+        while true {
+            println!("iteration");
+        }
+    });
+
+    thread::spawn(|| {
+        loop {
+            println!("iteration");
+        }
+    });
+}
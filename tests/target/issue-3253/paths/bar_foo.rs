@@ -0,0 +1,3 @@
+fn foo_decl_item(x: &mut i32) {
+    x = 3;
+}
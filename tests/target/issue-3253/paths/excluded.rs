@@ -0,0 +1,17 @@
+// This module is not imported in the cfg_if macro in lib.rs so it is ignored
+// while the foo and bar mods are formatted.
+// Check the corresponding file in tests/source/issue-3253/paths/excluded.rs
+
+
+
+
+    fn Foo<T>() where T: Bar {
+}
+
+
+
+trait CoolerTypes { fn dummy(&self) {
+}
+}
+
+
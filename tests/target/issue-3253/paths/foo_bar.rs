@@ -0,0 +1,5 @@
+fn Foo<T>()
+where
+    T: Bar,
+{
+}
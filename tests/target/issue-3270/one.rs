@@ -0,0 +1,12 @@
+// rustfmt-version: One
+
+pub fn main() {
+    /*   let s = String::from(
+            "
+    hello
+    world
+    ",
+        ); */
+
+    assert_eq!(s, "\nhello\nworld\n");
+}
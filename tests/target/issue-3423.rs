@@ -0,0 +1,5 @@
+/* a nice comment with a trailing whitespace */
+fn foo() {}
+
+/* a nice comment with a trailing tab */
+fn bar() {}
@@ -0,0 +1,42 @@
+fn main() {
+    ((((((((((((((((((((((((((((((((((((((((((0) + 1) + 1)
+        + 1)
+        + 1)
+        + 1)
+        + 1)
+        + 1)
+        + 1)
+        + 1)
+        + 1)
+        + 1)
+        + 1)
+        + 1)
+        + 1)
+        + 1)
+        + 1)
+        + 1)
+        + 1)
+        + 1)
+        + 1)
+        + 1)
+        + 1)
+        + 1)
+        + 1)
+        + 1)
+        + 1)
+        + 1)
+        + 1)
+        + 1)
+        + 1)
+        + 1)
+        + 1)
+        + 1)
+        + 1)
+        + 1)
+        + 1)
+        + 1)
+        + 1)
+        + 1)
+        + 1)
+        + 1);
+}
@@ -0,0 +1,6 @@
+// rustfmt-reorder_imports: false
+
+use std::fmt::{self, Display};
+use std::collections::HashMap;
+
+fn main() {}
@@ -0,0 +1,6 @@
+fn foo(a: T) {
+    match a {
+        1 => {}
+        0 => {} // _ => panic!("doesn't format!"),
+    }
+}
@@ -0,0 +1,4 @@
+#![feature(const_generics)]
+
+pub struct S<const N: usize>;
+impl S<{ 0 }> {}
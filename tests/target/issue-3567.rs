@@ -0,0 +1,3 @@
+fn check() {
+    vec![vec!(0; 10); 10];
+}
@@ -0,0 +1,5 @@
+// rustfmt-inline_attribute_width: 100
+
+#[macro_use] use static_assertions;
+
+#[cfg(unix)] use static_assertions;
@@ -0,0 +1,15 @@
+// rustfmt-version: One
+
+fn main() {
+    let toto = || {
+        if true {
+            42
+        } else {
+            24
+        }
+    };
+
+    {
+        T
+    }
+}
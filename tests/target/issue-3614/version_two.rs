@@ -0,0 +1,8 @@
+// rustfmt-version: Two
+
+fn main() {
+    let toto = || {
+        if true { 42 } else { 24 }
+    };
+    { T }
+}
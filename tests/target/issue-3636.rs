@@ -0,0 +1,8 @@
+// rustfmt-file_lines: [{"file":"tests/source/issue-3636.rs","range":[4,7]},{"file":"tests/target/issue-3636.rs","range":[3,6]}]
+
+fn foo() {
+    let x = 42;
+    let y = 42;
+    let z = "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+    let z = "bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb";   
+}
@@ -0,0 +1,7 @@
+fn f() -> Box<
+    dyn FnMut() -> Thing<
+        WithType = LongItemName,
+        Error = LONGLONGLONGLONGLONGONGEvenLongerErrorNameLongerLonger,
+    >,
+> {
+}
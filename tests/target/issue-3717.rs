@@ -0,0 +1,7 @@
+fn main() {
+    {
+        #[rustfmt::skip]
+        let _ = 
+        [1];
+    }
+}
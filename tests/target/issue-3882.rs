@@ -0,0 +1,4 @@
+// rustfmt-version: Two
+fn bar(_t: T, // bar
+) {
+}
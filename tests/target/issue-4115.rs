@@ -0,0 +1,8 @@
+#[derive(
+    A,
+    B,
+    C,
+    D,
+    // E,
+)]
+fn foo() {}
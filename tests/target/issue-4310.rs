@@ -0,0 +1,9 @@
+#![feature(const_generics)]
+
+fn foo<
+    const N: [u8; {
+        struct Inner<'a>(&'a ());
+        3
+    }],
+>() {
+}
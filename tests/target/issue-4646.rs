@@ -0,0 +1,20 @@
+trait Foo {
+    fn bar(&self)
+    // where
+    //     Self: Bar
+    ;
+}
+
+trait Foo {
+    fn bar(&self)
+    // where
+    //     Self: Bar
+    ;
+}
+
+trait Foo {
+    fn bar(&self)
+    // where
+    //     Self: Bar
+    ;
+}
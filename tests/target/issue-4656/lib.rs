@@ -0,0 +1,7 @@
+extern crate cfg_if;
+
+cfg_if::cfg_if! {
+    if #[cfg(target_family = "unix")] {
+        mod format_me_please;
+    }
+}
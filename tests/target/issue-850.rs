@@ -0,0 +1 @@
+const unsafe fn x() {}
@@ -0,0 +1,16 @@
+// rustfmt-normalize_comments: true
+
+trait NameC {
+    // comment
+}
+struct FooC {
+    // comment
+}
+enum MooC {
+    // comment
+}
+mod BarC { // comment
+}
+extern "C" {
+    // comment
+}
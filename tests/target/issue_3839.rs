@@ -0,0 +1,8 @@
+struct Foo {
+    a: i32,
+    /*
+    asd
+    */
+    // foo
+    b: i32,
+}
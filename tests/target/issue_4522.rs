@@ -0,0 +1,6 @@
+fn main() {
+    #[cfg(feature = "foo")]
+    {
+        // let foo = 0;
+    }
+}
@@ -0,0 +1,5 @@
+#[derive(Debug, Foo<T>)]
+enum Bar {}
+
+#[derive(Debug, , Default)]
+struct Struct(i32);
@@ -0,0 +1,8 @@
+macro_rules! foo {
+    ($s:ident ( $p:pat )) => {
+        Foo {
+            name: Name::$s($p),
+            ..
+        }
+    };
+}
@@ -0,0 +1,5 @@
+// rustfmt-config: issue-3802.toml
+
+fn main() {
+    println!("Hello world!");
+}
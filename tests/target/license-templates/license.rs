@@ -0,0 +1,6 @@
+// rustfmt-license_template_path: tests/license-template/lt.txt
+// Copyright 2019 The rustfmt developers.
+
+fn main() {
+    println!("Hello world!");
+}
@@ -0,0 +1,15 @@
+// rustfmt-format_strings: true
+// rustfmt-max_width: 80
+// rustfmt-control_brace_style: AlwaysNextLine
+
+fn main() {
+    match x
+    {
+        aaaaaaaa::Bbbbb::Ccccccccccccc(_, Some(ref x))
+            if x == "aaaaaaaaaaa aaaaaaa aaaaaa" =>
+        {
+            Ok(())
+        }
+        _ => Err(x),
+    }
+}
@@ -0,0 +1,3 @@
+// rustfmt-reorder_imports: false
+
+pub use self::super::super::super::root::mozilla::detail::StringClassFlags as nsTStringRepr_ClassFlags;
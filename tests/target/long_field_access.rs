@@ -0,0 +1,4 @@
+fn f() {
+    block_flow.base.stacking_relative_position_of_display_port =
+        self.base.stacking_relative_position_of_display_port;
+}
@@ -0,0 +1,2 @@
+// rustfmt-skip_children: true
+mod nested_skipped;
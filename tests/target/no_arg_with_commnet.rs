@@ -0,0 +1 @@
+fn foo(/* cooment */) {}
@@ -0,0 +1,9 @@
+// #2953
+
+macro_rules! demo {
+    ($a:ident <- $b:expr) => {};
+}
+
+fn main() {
+    demo!(i <- 0);
+}
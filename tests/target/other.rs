@@ -0,0 +1,5 @@
+// Part of multiple.rs
+
+fn bob() {
+    println!("hello other!");
+}
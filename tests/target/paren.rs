@@ -0,0 +1,6 @@
+fn main() {
+    let x = (1);
+    let y = (/* comment */(2));
+    let z = ((3)/* comment */);
+    let a = (4/* comment */);
+}
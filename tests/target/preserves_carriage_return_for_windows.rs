@@ -0,0 +1,2 @@
+// rustfmt-newline_style: Windows
+// FooBar
@@ -0,0 +1,7 @@
+#!/usr/bin/env run-cargo-script --with  /*options*/  // kept as is
+// rustfmt-normalize_comments: true
+
+// A block comment
+fn main() {
+    println!("Hello");
+}
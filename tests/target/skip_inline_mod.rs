@@ -0,0 +1,17 @@
+// Inline modules marked with `#![rustfmt::skip]` keep their contents as is.
+
+pub mod generated {
+    #![rustfmt::skip]
+    pub fn   foo ( ) -> u32 { 1 }
+        const  BAR : u32=2;
+}
+
+mod outer {
+    #[cfg(test)]
+    mod inner {
+        #![rustfmt::skip]
+        fn   baz ( ) { }
+    }
+
+    fn formatted() {}
+}
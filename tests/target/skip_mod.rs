@@ -0,0 +1,3 @@
+#![rustfmt::skip]
+use a ::  b
+;
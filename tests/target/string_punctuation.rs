@@ -0,0 +1,24 @@
+// rustfmt-format_strings: true
+
+fn main() {
+    println!(
+        "ThisIsAReallyLongStringWithNoSpaces.It_should_prefer_to_break_onpunctuation:\
+         Likethisssssssssssss"
+    );
+    format!("{}__{}__{}ItShouldOnlyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyyNoticeSemicolonsPeriodsColonsAndCommasAndResortToMid-CharBreaksAfterPunctuation{}{}",x,y,z,a,b);
+    println!(
+        "aaaaaaaaaaaaaaaaaaaaaaaaaaaaalhijalfhiigjapdighjapdigjapdighdapighapdighpaidhg;\
+         adopgihadoguaadbadgad,qeoihapethae8t0aet8haetadbjtaeg;\
+         ooeouthaoeutgadlgajduabgoiuadogabudogubaodugbadgadgadga;adoughaoeugbaouea"
+    );
+    println!(
+        "sentuhaesnuthaesnutheasunteahusnaethuseantuihaesntdiastnidaetnuhaideuhsenathe。\
+         WeShouldSupportNonAsciiPunctuations§\
+         ensuhatheasunteahsuneathusneathuasnuhaesnuhaesnuaethusnaetuheasnuth"
+    );
+    println!(
+        "ThisIsASampleOfCJKString.祇園精舍の鐘の声、諸行無常の響きあり。娑羅双樹の花の色、\
+         盛者必衰の理をあらはす。奢れる人も久しからず、ただ春の夜の夢のごとし。\
+         猛き者もつひにはほろびぬ、ひとへに風の前の塵に同じ。"
+    );
+}
@@ -0,0 +1,5 @@
+// rustfmt-version: Two
+
+fn issue_4355() {
+    let _ = ((1,),).0.0;
+}